@@ -0,0 +1,84 @@
+//!
+//! The Foundry contract artifact.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::build::contract::Contract as ContractBuild;
+use crate::linker::References as LinkReferences;
+
+///
+/// The Foundry contract artifact.
+///
+/// The zkEVM has no separate runtime code, so the deployed bytecode is the same as the bytecode.
+///
+/// The link references are nested into the bytecode objects, where Foundry reads them from,
+/// and duplicated at the top level like in the Hardhat artifact.
+///
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Foundry {
+    /// The artifact format identifier.
+    #[serde(rename = "_format")]
+    pub format: &'static str,
+    /// The contract name.
+    pub contract_name: String,
+    /// The source file name.
+    pub source_name: String,
+    /// The ABI specification JSON.
+    pub abi: serde_json::Value,
+    /// The bytecode.
+    pub bytecode: Bytecode,
+    /// The deployed bytecode.
+    pub deployed_bytecode: Bytecode,
+    /// The library link references.
+    pub link_references: LinkReferences,
+    /// The bytecode hash.
+    pub hash: String,
+    /// The factory dependencies, where the keys are the hashes and the values are paths.
+    pub factory_deps: BTreeMap<String, String>,
+}
+
+///
+/// The Foundry contract artifact bytecode.
+///
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    /// The `0x`-prefixed hexadecimal bytecode.
+    pub object: String,
+    /// The library link references.
+    pub link_references: LinkReferences,
+}
+
+impl Foundry {
+    /// The artifact format identifier.
+    pub const FORMAT: &'static str = "foundry-zksolc-artifact-1";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(contract: &ContractBuild) -> Self {
+        let bytecode = Bytecode {
+            object: format!("0x{}", hex::encode(contract.build.bytecode.as_slice())),
+            link_references: contract.link_references.clone(),
+        };
+
+        Self {
+            format: Self::FORMAT,
+            contract_name: contract.contract_name(),
+            source_name: contract.source_name(),
+            abi: contract
+                .abi
+                .clone()
+                .unwrap_or_else(|| serde_json::Value::Array(vec![])),
+            bytecode: bytecode.clone(),
+            deployed_bytecode: bytecode,
+            link_references: contract.link_references.clone(),
+            hash: contract.build.hash.to_owned(),
+            factory_deps: contract.build.factory_dependencies.clone(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! The Hardhat contract artifact.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::build::contract::Contract as ContractBuild;
+use crate::linker::References as LinkReferences;
+
+///
+/// The Hardhat contract artifact.
+///
+/// The zkEVM has no separate runtime code, so the deployed bytecode is the same as the bytecode.
+///
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hardhat {
+    /// The artifact format identifier.
+    #[serde(rename = "_format")]
+    pub format: &'static str,
+    /// The contract name.
+    pub contract_name: String,
+    /// The source file name.
+    pub source_name: String,
+    /// The ABI specification JSON.
+    pub abi: serde_json::Value,
+    /// The `0x`-prefixed hexadecimal bytecode.
+    pub bytecode: String,
+    /// The `0x`-prefixed hexadecimal deployed bytecode.
+    pub deployed_bytecode: String,
+    /// The library link references.
+    pub link_references: LinkReferences,
+    /// The deployed bytecode library link references.
+    pub deployed_link_references: LinkReferences,
+    /// The factory dependencies, where the keys are the hashes and the values are paths.
+    pub factory_deps: BTreeMap<String, String>,
+}
+
+impl Hardhat {
+    /// The artifact format identifier.
+    pub const FORMAT: &'static str = "hh-zksolc-artifact-1";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(contract: &ContractBuild) -> Self {
+        let bytecode = format!("0x{}", hex::encode(contract.build.bytecode.as_slice()));
+
+        Self {
+            format: Self::FORMAT,
+            contract_name: contract.contract_name(),
+            source_name: contract.source_name(),
+            abi: contract
+                .abi
+                .clone()
+                .unwrap_or_else(|| serde_json::Value::Array(vec![])),
+            bytecode: bytecode.clone(),
+            deployed_bytecode: bytecode,
+            link_references: contract.link_references.clone(),
+            deployed_link_references: contract.link_references.clone(),
+            factory_deps: contract.build.factory_dependencies.clone(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//!
+//! The toolchain-specific contract artifact.
+//!
+
+pub mod foundry;
+pub mod hardhat;
+
+use std::str::FromStr;
+
+use crate::build::contract::Contract as ContractBuild;
+use crate::linker::References as LinkReferences;
+
+use self::foundry::Foundry;
+use self::hardhat::Hardhat;
+
+///
+/// The toolchain-specific contract artifact format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The Hardhat artifact format.
+    Hardhat,
+    /// The Foundry artifact format.
+    Foundry,
+}
+
+impl Format {
+    /// The artifact file extension.
+    pub const EXTENSION: &'static str = "json";
+
+    ///
+    /// Serializes the contract artifact in the format.
+    ///
+    pub fn serialize(&self, contract: &ContractBuild) -> Vec<u8> {
+        match self {
+            Self::Hardhat => serde_json::to_vec_pretty(&Hardhat::new(contract)),
+            Self::Foundry => serde_json::to_vec_pretty(&Foundry::new(contract)),
+        }
+        .expect("Always valid")
+    }
+
+    ///
+    /// Updates the bytecode, hash, and link references of the serialized `artifact` of either
+    /// format, after its bytecode has been linked.
+    ///
+    pub fn update_linked(
+        artifact: &mut serde_json::Value,
+        bytecode: &[u8],
+        hash: &str,
+        link_references: &LinkReferences,
+    ) -> anyhow::Result<()> {
+        let bytecode = serde_json::Value::String(format!("0x{}", hex::encode(bytecode)));
+        let link_references = serde_json::to_value(link_references).expect("Always valid");
+        match artifact.get("_format").and_then(serde_json::Value::as_str) {
+            Some(Hardhat::FORMAT) => {
+                artifact["bytecode"] = bytecode.clone();
+                artifact["deployedBytecode"] = bytecode;
+                artifact["linkReferences"] = link_references.clone();
+                artifact["deployedLinkReferences"] = link_references;
+            }
+            Some(Foundry::FORMAT) => {
+                for key in ["bytecode", "deployedBytecode"] {
+                    artifact[key]["object"] = bytecode.clone();
+                    artifact[key]["linkReferences"] = link_references.clone();
+                }
+                artifact["linkReferences"] = link_references;
+                artifact["hash"] = serde_json::Value::String(hash.to_owned());
+            }
+            format => anyhow::bail!("Unknown artifact format {:?}", format),
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "hardhat" => Ok(Self::Hardhat),
+            "foundry" => Ok(Self::Foundry),
+            _ => Err(anyhow::anyhow!(
+                "Invalid artifact format `{}`. Available formats: hardhat, foundry",
+                string
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hardhat => write!(f, "hardhat"),
+            Self::Foundry => write!(f, "foundry"),
+        }
+    }
+}
@@ -4,9 +4,17 @@
 
 use std::fs::File;
 use std::io::Write;
+use std::path::Component;
 use std::path::Path;
+use std::path::PathBuf;
 
+use crate::build::artifact::Format as ArtifactFormat;
+use crate::diagnostic::Diagnostic;
+use crate::linker::References as LinkReferences;
+use crate::project::contract::statistics::Statistics;
+use crate::project::metadata::Metadata;
 use crate::solc::combined_json::contract::Contract as CombinedJsonContract;
+use crate::solc::standard_json::output::contract::evm::bytecode::Bytecode as StandardJsonOutputContractEVMBytecode;
 use crate::solc::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
 use crate::solc::standard_json::output::contract::Contract as StandardJsonOutputContract;
 
@@ -23,6 +31,14 @@ pub struct Contract {
     pub build: compiler_llvm_context::Build,
     /// The ABI specification JSON.
     pub abi: Option<serde_json::Value>,
+    /// The project metadata.
+    pub metadata: Option<Metadata>,
+    /// The placeholders of the unlinked libraries in the bytecode.
+    pub link_references: LinkReferences,
+    /// The compilation statistics.
+    pub statistics: Statistics,
+    /// The compilation warnings, which are moved to the project build.
+    pub warnings: Vec<Diagnostic>,
 }
 
 impl Contract {
@@ -34,97 +50,72 @@ impl Contract {
         identifier: String,
         build: compiler_llvm_context::Build,
         abi: Option<serde_json::Value>,
+        metadata: Option<Metadata>,
+        link_references: LinkReferences,
+        statistics: Statistics,
+        warnings: Vec<Diagnostic>,
     ) -> Self {
         Self {
             path,
             identifier,
             build,
             abi,
+            metadata,
+            link_references,
+            statistics,
+            warnings,
         }
     }
 
     ///
     /// Writes the contract text assembly and bytecode to files.
     ///
+    /// The files are placed according to `file_path`.
+    ///
     pub fn write_to_directory(
         self,
         path: &Path,
         output_assembly: bool,
         output_binary: bool,
         output_abi: bool,
+        artifact_format: Option<ArtifactFormat>,
         overwrite: bool,
     ) -> anyhow::Result<()> {
-        let file_name = Self::short_path(self.path.as_str());
+        if let Some(artifact_format) = artifact_format {
+            Self::write_file(
+                path,
+                self.file_path(ArtifactFormat::EXTENSION),
+                artifact_format.serialize(&self).as_slice(),
+                overwrite,
+            )?;
+        }
 
         if output_assembly {
-            let file_name = format!(
-                "{}.{}",
-                file_name,
-                compiler_common::EXTENSION_ZKEVM_ASSEMBLY
-            );
-            let mut file_path = path.to_owned();
-            file_path.push(file_name);
-
-            if file_path.exists() && !overwrite {
-                eprintln!(
-                    "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
-                    file_path
-                );
-            } else {
-                File::create(&file_path)
-                    .map_err(|error| {
-                        anyhow::anyhow!("File {:?} creating error: {}", file_path, error)
-                    })?
-                    .write_all(self.build.assembly_text.as_bytes())
-                    .map_err(|error| {
-                        anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
-                    })?;
-            }
+            Self::write_file(
+                path,
+                self.file_path(compiler_common::EXTENSION_ZKEVM_ASSEMBLY),
+                self.build.assembly_text.as_bytes(),
+                overwrite,
+            )?;
         }
 
         if output_binary {
-            let file_name = format!("{}.{}", file_name, compiler_common::EXTENSION_ZKEVM_BINARY);
-            let mut file_path = path.to_owned();
-            file_path.push(file_name);
-
-            if file_path.exists() && !overwrite {
-                eprintln!(
-                    "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
-                    file_path
-                );
-            } else {
-                File::create(&file_path)
-                    .map_err(|error| {
-                        anyhow::anyhow!("File {:?} creating error: {}", file_path, error)
-                    })?
-                    .write_all(self.build.bytecode.as_slice())
-                    .map_err(|error| {
-                        anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
-                    })?;
-            }
+            Self::write_file(
+                path,
+                self.file_path(compiler_common::EXTENSION_ZKEVM_BINARY),
+                self.build.bytecode.as_slice(),
+                overwrite,
+            )?;
         }
 
-        if let Some(abi) = self.abi {
+        if let Some(abi) = self.abi.as_ref() {
             if output_abi {
-                let file_name = format!("{}.{}", file_name, compiler_common::EXTENSION_ABI);
-                let mut file_path = path.to_owned();
-                file_path.push(file_name);
-
-                if file_path.exists() && !overwrite {
-                    eprintln!(
-                        "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
-                        file_path
-                    );
-                } else {
-                    File::create(&file_path)
-                        .map_err(|error| {
-                            anyhow::anyhow!("File {:?} creating error: {}", file_path, error)
-                        })?
-                        .write_all(abi.to_string().as_bytes())
-                        .map_err(|error| {
-                            anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
-                        })?;
-                }
+                Self::write_file(
+                    path,
+                    self.file_path(compiler_common::EXTENSION_ABI),
+                    abi.to_string().as_bytes(),
+                    overwrite,
+                )?;
             }
         }
 
@@ -171,22 +162,145 @@ impl Contract {
     ) -> anyhow::Result<()> {
         let bytecode = hex::encode(self.build.bytecode.as_slice());
 
-        standard_json_contract.ir_optimized = None;
         standard_json_contract.abi = self.abi;
-        standard_json_contract.evm =
-            Some(StandardJsonOutputContractEVM::new_zkevm_bytecode(bytecode));
+        match standard_json_contract.evm.as_mut() {
+            Some(evm) => evm.bytecode = Some(StandardJsonOutputContractEVMBytecode::new(bytecode)),
+            None => {
+                standard_json_contract.evm =
+                    Some(StandardJsonOutputContractEVM::new_zkevm_bytecode(bytecode))
+            }
+        }
+        if let Some(bytecode) = standard_json_contract
+            .evm
+            .as_mut()
+            .and_then(|evm| evm.bytecode.as_mut())
+        {
+            bytecode.link_references = self.link_references;
+        }
         standard_json_contract.factory_dependencies = Some(self.build.factory_dependencies);
         standard_json_contract.hash = Some(self.build.hash);
+        standard_json_contract.size = Some(self.size_in_words());
+        standard_json_contract.zksolc_metadata = self
+            .metadata
+            .map(|metadata| serde_json::to_value(metadata).expect("Always valid"));
 
         Ok(())
     }
 
     ///
-    /// Converts the full path to a short one.
+    /// Returns the bytecode size in words.
+    ///
+    pub fn size_in_words(&self) -> usize {
+        (self.build.bytecode.len() + compiler_common::SIZE_FIELD - 1) / compiler_common::SIZE_FIELD
+    }
+
+    ///
+    /// Returns the artifact file path relative to the output directory.
+    ///
+    /// The layout mirrors the source tree, with one directory per source file and one file
+    /// per contract, e.g. `contracts/Token.sol/Token.zbin`.
+    ///
+    pub fn file_path(&self, extension: &str) -> PathBuf {
+        let mut file_path = self.source_path();
+        file_path.push(format!("{}.{}", self.contract_name(), extension));
+        file_path
+    }
+
+    ///
+    /// Returns the source file name, e.g. `contracts/Token.sol`.
+    ///
+    pub fn source_name(&self) -> String {
+        self.source_path()
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    ///
+    /// Returns the contract name.
+    ///
+    /// In Yul mode, where the path has no contract name, the file stem is used.
+    ///
+    pub fn contract_name(&self) -> String {
+        match self.split_path() {
+            (_, Some(name)) => name.to_owned(),
+            (source_path, None) => source_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.identifier.to_owned()),
+        }
+    }
+
+    ///
+    /// Returns the source file path.
+    ///
+    /// The path is taken relative to the current directory, if possible, and the root and
+    /// parent components are omitted.
+    ///
+    fn source_path(&self) -> PathBuf {
+        let (source_path, _) = self.split_path();
+        let source_path = std::env::current_dir()
+            .ok()
+            .and_then(|directory| source_path.strip_prefix(directory).ok())
+            .unwrap_or(source_path);
+        source_path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(component) => Some(component),
+                _ => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Splits the `path:Name` contract path into the source file path and the contract name.
+    ///
+    fn split_path(&self) -> (&Path, Option<&str>) {
+        match self.path.rsplit_once(':') {
+            Some((source_path, name))
+                if !name.is_empty()
+                    && name.chars().all(|character| {
+                        character.is_alphanumeric() || "_$".contains(character)
+                    }) =>
+            {
+                (Path::new(source_path), Some(name))
+            }
+            _ => (Path::new(self.path.as_str()), None),
+        }
+    }
+
+    ///
+    /// Writes the file, creating its parent directories.
+    ///
+    /// Does not touch the existing file, unless `overwrite` is set.
     ///
-    pub fn short_path(path: &str) -> &str {
-        path.rfind('/')
-            .map(|last_slash| &path[last_slash + 1..])
-            .unwrap_or_else(|| path)
+    fn write_file(
+        directory: &Path,
+        relative_path: PathBuf,
+        data: &[u8],
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let file_path = directory.join(relative_path);
+
+        if file_path.exists() && !overwrite {
+            eprintln!(
+                "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
+                file_path
+            );
+            return Ok(());
+        }
+
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|error| {
+                anyhow::anyhow!("Directory {:?} creating error: {}", parent, error)
+            })?;
+        }
+        File::create(&file_path)
+            .map_err(|error| anyhow::anyhow!("File {:?} creating error: {}", file_path, error))?
+            .write_all(data)
+            .map_err(|error| anyhow::anyhow!("File {:?} writing error: {}", file_path, error))?;
+
+        Ok(())
     }
 }
@@ -0,0 +1,104 @@
+//!
+//! The build manifest contract.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::build::artifact::Format as ArtifactFormat;
+use crate::build::contract::Contract as ContractBuild;
+use crate::linker::References as LinkReferences;
+
+///
+/// The build manifest contract.
+///
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The auxiliary identifier, e.g. the Yul object name.
+    pub identifier: String,
+    /// The bytecode hash.
+    pub hash: String,
+    /// The factory dependencies, where the keys are the hashes and the values are paths.
+    pub factory_dependencies: BTreeMap<String, String>,
+    /// The placeholders of the unlinked libraries in the bytecode.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub link_references: LinkReferences,
+    /// The assembly file path, relative to the output directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assembly: Option<String>,
+    /// The bytecode file path, relative to the output directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary: Option<String>,
+    /// The ABI file path, relative to the output directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<String>,
+    /// The toolchain-specific artifact file path, relative to the output directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<String>,
+}
+
+impl Contract {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Only the files which are written are listed.
+    ///
+    pub fn new(
+        contract: &ContractBuild,
+        output_assembly: bool,
+        output_binary: bool,
+        output_abi: bool,
+        artifact_format: Option<ArtifactFormat>,
+    ) -> Self {
+        Self {
+            identifier: contract.identifier.to_owned(),
+            hash: contract.build.hash.to_owned(),
+            factory_dependencies: contract
+                .build
+                .factory_dependencies
+                .iter()
+                .map(|(hash, path)| (hash.to_owned(), path.to_owned()))
+                .collect(),
+            link_references: contract.link_references.clone(),
+            assembly: if output_assembly {
+                Some(
+                    contract
+                        .file_path(compiler_common::EXTENSION_ZKEVM_ASSEMBLY)
+                        .to_string_lossy()
+                        .to_string(),
+                )
+            } else {
+                None
+            },
+            binary: if output_binary {
+                Some(
+                    contract
+                        .file_path(compiler_common::EXTENSION_ZKEVM_BINARY)
+                        .to_string_lossy()
+                        .to_string(),
+                )
+            } else {
+                None
+            },
+            abi: if output_abi && contract.abi.is_some() {
+                Some(
+                    contract
+                        .file_path(compiler_common::EXTENSION_ABI)
+                        .to_string_lossy()
+                        .to_string(),
+                )
+            } else {
+                None
+            },
+            artifact: artifact_format.map(|_| {
+                contract
+                    .file_path(ArtifactFormat::EXTENSION)
+                    .to_string_lossy()
+                    .to_string()
+            }),
+        }
+    }
+}
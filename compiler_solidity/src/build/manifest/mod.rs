@@ -0,0 +1,182 @@
+//!
+//! The build manifest.
+//!
+
+pub mod contract;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::build::artifact::Format as ArtifactFormat;
+use crate::project::metadata::Metadata;
+
+use self::contract::Contract;
+
+///
+/// The build manifest.
+///
+/// Written along with the artifacts, so the deploy scripts can find them without parsing
+/// the file names.
+///
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    /// The project metadata with the compiler versions, pipeline, and optimizer settings.
+    #[serde(flatten)]
+    pub metadata: Option<Metadata>,
+    /// The contracts, where the keys are the full contract paths.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl Manifest {
+    /// The manifest file name.
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(metadata: Option<Metadata>) -> Self {
+        Self {
+            metadata,
+            contracts: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Writes the manifest to the specified directory.
+    ///
+    pub fn write_to_directory(&self, path: &Path, overwrite: bool) -> anyhow::Result<()> {
+        let mut file_path = path.to_owned();
+        file_path.push(Self::FILE_NAME);
+
+        if file_path.exists() && !overwrite {
+            eprintln!(
+                "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
+                file_path
+            );
+            return Ok(());
+        }
+
+        let json = serde_json::to_vec_pretty(self).expect("Always valid");
+        File::create(&file_path)
+            .map_err(|error| anyhow::anyhow!("File {:?} creating error: {}", file_path, error))?
+            .write_all(json.as_slice())
+            .map_err(|error| anyhow::anyhow!("File {:?} writing error: {}", file_path, error))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Finds the manifest listing the bytecode file at `binary_path`.
+    ///
+    /// The manifest is looked up in the directories containing the bytecode file.
+    /// Returns the manifest directory, the manifest, and the path of the contract.
+    ///
+    pub fn find(binary_path: &Path) -> anyhow::Result<Option<(PathBuf, Self, String)>> {
+        for directory in binary_path.ancestors().skip(1) {
+            let file_path = directory.join(Self::FILE_NAME);
+            if !file_path.exists() {
+                continue;
+            }
+
+            let json = std::fs::read(&file_path).map_err(|error| {
+                anyhow::anyhow!("File {:?} reading error: {}", file_path, error)
+            })?;
+            let manifest: Self = serde_json::from_slice(json.as_slice()).map_err(|error| {
+                anyhow::anyhow!("File {:?} parsing error: {}", file_path, error)
+            })?;
+            let path = manifest
+                .contracts
+                .iter()
+                .find(|(_, contract)| {
+                    contract
+                        .binary
+                        .as_ref()
+                        .map(|binary| directory.join(binary) == binary_path)
+                        .unwrap_or_default()
+                })
+                .map(|(path, _)| path.to_owned());
+            if let Some(path) = path {
+                return Ok(Some((directory.to_owned(), manifest, path)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    ///
+    /// Returns the paths of the contracts which have the `path` contract as a factory dependency.
+    ///
+    pub fn dependents(&self, path: &str) -> Vec<String> {
+        self.contracts
+            .iter()
+            .filter(|(_, contract)| {
+                contract
+                    .factory_dependencies
+                    .values()
+                    .any(|dependency| dependency.as_str() == path)
+            })
+            .map(|(path, _)| path.to_owned())
+            .collect()
+    }
+
+    ///
+    /// Updates the `path` contract, whose bytecode has been linked with the `linked` libraries.
+    ///
+    /// The hash, link references, and toolchain-specific artifact are updated, and the manifest
+    /// is written back to `directory`.
+    ///
+    pub fn update_linked(
+        mut self,
+        directory: &Path,
+        path: &str,
+        bytecode: &[u8],
+        hash: &str,
+        linked: &BTreeSet<String>,
+    ) -> anyhow::Result<()> {
+        let contract = self
+            .contracts
+            .get_mut(path)
+            .ok_or_else(|| anyhow::anyhow!("Contract `{}` not found in the manifest", path))?;
+
+        contract.hash = hash.to_owned();
+        for path in linked.iter() {
+            let (file, name) = path.rsplit_once(':').unwrap_or(("", path.as_str()));
+            if let Some(contracts) = contract.link_references.get_mut(file) {
+                contracts.remove(name);
+                if contracts.is_empty() {
+                    contract.link_references.remove(file);
+                }
+            }
+        }
+
+        if let Some(artifact) = contract.artifact.as_ref() {
+            let file_path = directory.join(artifact);
+            let json = std::fs::read(&file_path).map_err(|error| {
+                anyhow::anyhow!("File {:?} reading error: {}", file_path, error)
+            })?;
+            let mut artifact: serde_json::Value =
+                serde_json::from_slice(json.as_slice()).map_err(|error| {
+                    anyhow::anyhow!("File {:?} parsing error: {}", file_path, error)
+                })?;
+            ArtifactFormat::update_linked(&mut artifact, bytecode, hash, &contract.link_references)
+                .map_err(|error| {
+                    anyhow::anyhow!("File {:?} updating error: {}", file_path, error)
+                })?;
+            std::fs::write(
+                &file_path,
+                serde_json::to_vec_pretty(&artifact).expect("Always valid"),
+            )
+            .map_err(|error| anyhow::anyhow!("File {:?} writing error: {}", file_path, error))?;
+        }
+
+        self.write_to_directory(directory, true)
+    }
+}
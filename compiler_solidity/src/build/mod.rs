@@ -2,15 +2,27 @@
 //! The Solidity project build.
 //!
 
+pub mod artifact;
 pub mod contract;
+pub mod manifest;
 
 use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
 use std::path::Path;
 
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::list::List as DiagnosticList;
+use crate::diagnostic::Diagnostic;
+use crate::project::contract::statistics::Statistics;
+use crate::project::metadata::Metadata;
 use crate::solc::combined_json::CombinedJson;
 use crate::solc::standard_json::output::Output as StandardJsonOutput;
 
+use self::artifact::Format as ArtifactFormat;
 use self::contract::Contract;
+use self::manifest::contract::Contract as ManifestContract;
+use self::manifest::Manifest;
 
 ///
 /// The Solidity project build.
@@ -19,11 +31,74 @@ use self::contract::Contract;
 pub struct Build {
     /// The contract data,
     pub contracts: BTreeMap<String, Contract>,
+    /// The errors of the contracts which have failed to compile.
+    pub errors: Vec<Diagnostic>,
+    /// The warnings, which do not prevent the artifacts from being written.
+    pub warnings: Vec<Diagnostic>,
+    /// The project metadata.
+    pub metadata: Option<Metadata>,
 }
 
 impl Build {
+    /// The default bytecode size limit in words, as the length is encoded with 16 bits in the bytecode hash.
+    pub const BYTECODE_SIZE_LIMIT_DEFAULT: usize = u16::MAX as usize;
+
+    ///
+    /// Checks the bytecode size of every contract against the `limit` in words.
+    ///
+    /// The violations are reported as warnings, or as errors if `is_error` is set.
+    ///
+    pub fn check_bytecode_size(&mut self, limit: usize, is_error: bool) {
+        for (path, contract) in self.contracts.iter() {
+            let size = contract.size_in_words();
+            if size <= limit {
+                continue;
+            }
+
+            let message = format!(
+                "Contract `{}` bytecode size is {} words, which exceeds the limit of {} words",
+                path, size, limit
+            );
+            if is_error {
+                self.errors.push(
+                    Diagnostic::new_error(DiagnosticCode::BytecodeSize, message)
+                        .with_contract_path(path.as_str()),
+                );
+            } else {
+                self.warnings.push(
+                    Diagnostic::new_warning(DiagnosticCode::BytecodeSize, message)
+                        .with_contract_path(path.as_str()),
+                );
+            }
+        }
+    }
+
+    ///
+    /// Prints the warnings to `stderr`.
+    ///
+    pub fn print_warnings(&self) {
+        for warning in self.warnings.iter() {
+            eprintln!("{}", warning);
+        }
+    }
+
+    ///
+    /// Returns the build if all contracts have been compiled successfully.
+    ///
+    /// Otherwise, returns the errors of all failed contracts at once.
+    ///
+    pub fn check_errors(self) -> anyhow::Result<Self> {
+        if !self.errors.is_empty() {
+            return Err(DiagnosticList::new(self.errors).into());
+        }
+
+        Ok(self)
+    }
+
+    ///
+    /// Writes all contracts to the specified directory, along with the manifest.
     ///
-    /// Writes all contracts to the specified directory.
+    /// Nothing is written if several contracts are mapped to the same output path.
     ///
     pub fn write_to_directory(
         self,
@@ -31,17 +106,82 @@ impl Build {
         output_assembly: bool,
         output_binary: bool,
         output_abi: bool,
+        artifact_format: Option<ArtifactFormat>,
         overwrite: bool,
     ) -> anyhow::Result<()> {
-        for (_path, contract) in self.contracts.into_iter() {
+        self.check_output_collisions()?;
+
+        let mut manifest = Manifest::new(self.metadata);
+        for (path, contract) in self.contracts.into_iter() {
+            manifest.contracts.insert(
+                path,
+                ManifestContract::new(
+                    &contract,
+                    output_assembly,
+                    output_binary,
+                    output_abi,
+                    artifact_format,
+                ),
+            );
             contract.write_to_directory(
                 output_directory,
                 output_assembly,
                 output_binary,
                 output_abi,
+                artifact_format,
                 overwrite,
             )?;
         }
+        manifest.write_to_directory(output_directory, overwrite)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the compilation statistics report of all contracts to the specified file.
+    ///
+    pub fn write_statistics(&self, path: &Path) -> anyhow::Result<()> {
+        let statistics: BTreeMap<&str, &Statistics> = self
+            .contracts
+            .iter()
+            .map(|(path, contract)| (path.as_str(), &contract.statistics))
+            .collect();
+        let json = serde_json::to_vec_pretty(&statistics).expect("Always valid");
+        File::create(path)
+            .map_err(|error| anyhow::anyhow!("File {:?} creating error: {}", path, error))?
+            .write_all(json.as_slice())
+            .map_err(|error| anyhow::anyhow!("File {:?} writing error: {}", path, error))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Checks that every contract is written to its own output path.
+    ///
+    /// The paths are compared case-insensitively, as some filesystems are case-insensitive.
+    ///
+    fn check_output_collisions(&self) -> anyhow::Result<()> {
+        let mut output_paths: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for (path, contract) in self.contracts.iter() {
+            let output_path = contract
+                .file_path(compiler_common::EXTENSION_ZKEVM_BINARY)
+                .with_extension("")
+                .to_string_lossy()
+                .to_lowercase();
+            output_paths
+                .entry(output_path)
+                .or_default()
+                .push(path.as_str());
+        }
+
+        let collisions: Vec<String> = output_paths
+            .into_iter()
+            .filter(|(_, paths)| paths.len() > 1)
+            .map(|(output_path, paths)| format!("`{}`: {}", output_path, paths.join(", ")))
+            .collect();
+        if !collisions.is_empty() {
+            anyhow::bail!("Output path collision(s) found:\n{}", collisions.join("\n"));
+        }
 
         Ok(())
     }
@@ -72,10 +212,20 @@ impl Build {
     ///
     /// Writes all contracts assembly and bytecode to the standard JSON.
     ///
+    /// The errors of the failed contracts and the warnings are appended to the standard JSON
+    /// errors, so the artifacts of the successful contracts are still available.
+    ///
     pub fn write_to_standard_json(
         mut self,
         standard_json: &mut StandardJsonOutput,
     ) -> anyhow::Result<()> {
+        if !self.errors.is_empty() {
+            standard_json.push_error(DiagnosticList::new(self.errors).into());
+        }
+        if !self.warnings.is_empty() {
+            standard_json.push_error(DiagnosticList::new(self.warnings).into());
+        }
+
         let contracts = match standard_json.contracts.as_mut() {
             Some(contracts) => contracts,
             None => return Ok(()),
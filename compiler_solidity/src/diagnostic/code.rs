@@ -0,0 +1,87 @@
+//!
+//! The diagnostic code.
+//!
+
+///
+/// The diagnostic code.
+///
+/// Identifies the pipeline stage which has produced the diagnostic.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Code {
+    /// The Yul lexer or parser error.
+    YulParsing,
+    /// The Yul semantic analysis error.
+    YulAnalysis,
+    /// The Ethereal IR construction error.
+    EthIR,
+    /// The Ethereal IR block clone limit violation.
+    EthIRCloneLimit,
+    /// The Ethereal IR block truncation to `INVALID`.
+    EthIRTruncation,
+    /// The Ethereal IR internal function recovery failure, which falls back to inlining.
+    EthIRRecovery,
+    /// The LLVM IR generation or optimization error.
+    CodeGeneration,
+    /// The factory dependency resolution or compilation error.
+    Dependency,
+    /// The bytecode size limit violation.
+    BytecodeSize,
+    /// The bytecode with the placeholders of unlinked libraries.
+    UnlinkedLibraries,
+}
+
+impl Code {
+    ///
+    /// Returns the `solc --standard-json` output error type.
+    ///
+    pub fn r#type(&self) -> &'static str {
+        match self {
+            Self::YulParsing => "ParserError",
+            Self::YulAnalysis => "DeclarationError",
+            Self::EthIR => "CodeGenerationError",
+            Self::EthIRCloneLimit => "CodeGenerationError",
+            Self::EthIRTruncation => "CodeGenerationError",
+            Self::EthIRRecovery => "CodeGenerationError",
+            Self::CodeGeneration => "CodeGenerationError",
+            Self::Dependency => "CodeGenerationError",
+            Self::BytecodeSize => "CodeSizeError",
+            Self::UnlinkedLibraries => "LinkerError",
+        }
+    }
+
+    ///
+    /// Returns the `solc --standard-json` output error component.
+    ///
+    pub fn component(&self) -> &'static str {
+        match self {
+            Self::YulParsing => "yul",
+            Self::YulAnalysis => "yul",
+            Self::EthIR => "ethir",
+            Self::EthIRCloneLimit => "ethir",
+            Self::EthIRTruncation => "ethir",
+            Self::EthIRRecovery => "ethir",
+            Self::CodeGeneration => "llvm",
+            Self::Dependency => "general",
+            Self::BytecodeSize => "general",
+            Self::UnlinkedLibraries => "general",
+        }
+    }
+}
+
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YulParsing => write!(f, "ZK1001"),
+            Self::YulAnalysis => write!(f, "ZK1002"),
+            Self::EthIR => write!(f, "ZK2001"),
+            Self::EthIRCloneLimit => write!(f, "ZK2002"),
+            Self::EthIRTruncation => write!(f, "ZK2003"),
+            Self::EthIRRecovery => write!(f, "ZK2004"),
+            Self::CodeGeneration => write!(f, "ZK3001"),
+            Self::Dependency => write!(f, "ZK3002"),
+            Self::BytecodeSize => write!(f, "ZK3003"),
+            Self::UnlinkedLibraries => write!(f, "ZK3004"),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//!
+//! The compiler diagnostic list.
+//!
+
+use super::code::Code;
+use super::Diagnostic;
+
+///
+/// The compiler diagnostic list.
+///
+/// Used as an `anyhow::Error` payload, when a stage reports several diagnostics at once.
+///
+#[derive(Debug, Default, Clone)]
+pub struct List {
+    /// The diagnostics.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl List {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(diagnostics: Vec<Diagnostic>) -> Self {
+        Self { diagnostics }
+    }
+
+    ///
+    /// Extracts the diagnostics from an arbitrary error.
+    ///
+    /// The errors which are not diagnostics yet are converted to ones with the `code`.
+    /// The `contract_path` is set to the diagnostics which do not have one.
+    ///
+    pub fn from_error(error: anyhow::Error, code: Code, contract_path: Option<&str>) -> Self {
+        let diagnostics = match error.downcast::<Self>() {
+            Ok(list) => list.diagnostics,
+            Err(error) => match error.downcast::<Diagnostic>() {
+                Ok(diagnostic) => vec![diagnostic],
+                Err(error) => vec![Diagnostic::new_error(code, error.to_string())],
+            },
+        };
+
+        Self::new(
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| match contract_path {
+                    Some(contract_path) => diagnostic.with_contract_path(contract_path),
+                    None => diagnostic,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl std::fmt::Display for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for diagnostic in self.diagnostics.iter() {
+            write!(f, "{}", diagnostic)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for List {}
@@ -0,0 +1,129 @@
+//!
+//! The compiler diagnostic.
+//!
+
+pub mod code;
+pub mod list;
+pub mod severity;
+
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+
+use self::code::Code;
+use self::severity::Severity;
+
+///
+/// The compiler diagnostic.
+///
+/// Converted to the `solc --standard-json` output error, so the `zksolc` failures can be shown
+/// by the IDE integrations in the same way as the `solc` ones.
+///
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The diagnostic severity.
+    pub severity: Severity,
+    /// The diagnostic code.
+    pub code: Code,
+    /// The full path of the contract the diagnostic belongs to.
+    pub contract_path: Option<String>,
+    /// The Solidity source code location.
+    pub source_location: Option<SourceLocation>,
+    /// The non-formatted message.
+    pub message: String,
+}
+
+impl Diagnostic {
+    ///
+    /// A shortcut constructor for the error.
+    ///
+    pub fn new_error(code: Code, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            code,
+            contract_path: None,
+            source_location: None,
+            message,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for the warning.
+    ///
+    pub fn new_warning(code: Code, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            code,
+            contract_path: None,
+            source_location: None,
+            message,
+        }
+    }
+
+    ///
+    /// Sets the Solidity source code location.
+    ///
+    pub fn with_source_location(mut self, source_location: Option<SourceLocation>) -> Self {
+        self.source_location = source_location;
+        self
+    }
+
+    ///
+    /// Sets the contract path, unless it has been already set by a nested contract.
+    ///
+    pub fn with_contract_path(mut self, contract_path: &str) -> Self {
+        if self.contract_path.is_none() {
+            self.contract_path = Some(contract_path.to_owned());
+        }
+        self
+    }
+
+    ///
+    /// Returns the `solc --standard-json` output error type.
+    ///
+    /// The warnings have the `Warning` type, like the `solc` ones.
+    ///
+    pub fn r#type(&self) -> &'static str {
+        match self.severity {
+            Severity::Warning => "Warning",
+            _ => self.code.r#type(),
+        }
+    }
+
+    ///
+    /// Returns the `solc --standard-json` output error component.
+    ///
+    pub fn component(&self) -> &'static str {
+        self.code.component()
+    }
+}
+
+impl From<Diagnostic> for SolcStandardJsonOutputError {
+    fn from(diagnostic: Diagnostic) -> Self {
+        let r#type = diagnostic.r#type().to_owned();
+        Self {
+            component: diagnostic.component().to_owned(),
+            error_code: Some(diagnostic.code.to_string()),
+            formatted_message: diagnostic.to_string(),
+            message: diagnostic.message,
+            severity: diagnostic.severity.to_string(),
+            source_location: diagnostic.source_location,
+            r#type,
+        }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} ({}): {}", self.r#type(), self.code, self.message)?;
+        if let Some(ref source_location) = self.source_location {
+            writeln!(f, " --> {}", source_location)?;
+        }
+        if let Some(ref contract_path) = self.contract_path {
+            writeln!(f, "--> {}", contract_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
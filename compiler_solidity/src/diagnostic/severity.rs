@@ -0,0 +1,28 @@
+//!
+//! The diagnostic severity.
+//!
+
+///
+/// The diagnostic severity.
+///
+/// Uses the same values as the `severity` field of the `solc --standard-json` output error.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The compilation cannot proceed.
+    Error,
+    /// The compilation proceeds, but the code is likely to be incorrect.
+    Warning,
+    /// The informational message.
+    Info,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+            Self::Info => write!(f, "info"),
+        }
+    }
+}
@@ -2,6 +2,11 @@
 //! Translates the jump operations.
 //!
 
+use inkwell::types::BasicType;
+use inkwell::values::BasicValue;
+
+use crate::evm::ethereal_ir::function::call::Call;
+
 ///
 /// Translates the unconditional jump.
 ///
@@ -67,3 +72,159 @@ where
 
     Ok(None)
 }
+
+///
+/// Translates the internal function call.
+///
+/// `stack_height` is the stack height before the `JUMP`, where the return address is followed
+/// by the arguments and the destination tag. The return values replace the return address and
+/// the arguments, and the control flow continues at the return address block.
+///
+pub fn call<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    call: Call,
+    stack_height: usize,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let function = context
+        .functions
+        .get(call.name.as_str())
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Undeclared function `{}`", call.name))?;
+
+    let arguments_offset = stack_height - call.input_size - 1;
+    let mut arguments = Vec::with_capacity(call.input_size + 1);
+    for index in 0..call.input_size {
+        let pointer = context.evm().stack[arguments_offset + index]
+            .to_llvm()
+            .into_pointer_value();
+        let value = context.build_load(
+            pointer,
+            format!("{}_argument_{}", call.name, index).as_str(),
+        );
+        arguments.push(value);
+    }
+    if let Some(compiler_llvm_context::FunctionReturn::Compound { size, .. }) = function.r#return {
+        let r#type = context.structure_type(vec![context.field_type().as_basic_type_enum(); size]);
+        let pointer = context.build_alloca(
+            r#type,
+            format!("{}_return_pointer_argument", call.name).as_str(),
+        );
+        context.build_store(pointer, r#type.const_zero());
+        arguments.insert(0, pointer.as_basic_value_enum());
+    }
+
+    let return_value = context.build_invoke(
+        function.value,
+        arguments.as_slice(),
+        format!("{}_call", call.name).as_str(),
+    );
+
+    let outputs_offset = arguments_offset - 1;
+    match function.r#return {
+        Some(compiler_llvm_context::FunctionReturn::Compound { .. }) => {
+            let return_pointer = return_value
+                .ok_or_else(|| anyhow::anyhow!("Function `{}` return value is missing", call.name))?
+                .into_pointer_value();
+            for index in 0..call.output_size {
+                let pointer = unsafe {
+                    context.builder().build_gep(
+                        return_pointer,
+                        &[
+                            context.field_const(0),
+                            context
+                                .integer_type(compiler_common::BITLENGTH_X32)
+                                .const_int(index as u64, false),
+                        ],
+                        format!("{}_return_{}_gep_pointer", call.name, index).as_str(),
+                    )
+                };
+                let pointer = context.builder().build_pointer_cast(
+                    pointer,
+                    context
+                        .field_type()
+                        .ptr_type(compiler_llvm_context::AddressSpace::Stack.into()),
+                    format!("{}_return_{}_gep_pointer_field", call.name, index).as_str(),
+                );
+                let value =
+                    context.build_load(pointer, format!("{}_return_{}", call.name, index).as_str());
+                let stack_pointer = context.evm().stack[outputs_offset + index]
+                    .to_llvm()
+                    .into_pointer_value();
+                context.build_store(stack_pointer, value);
+            }
+        }
+        _ if call.output_size == 1 => {
+            let value = return_value.ok_or_else(|| {
+                anyhow::anyhow!("Function `{}` return value is missing", call.name)
+            })?;
+            let stack_pointer = context.evm().stack[outputs_offset]
+                .to_llvm()
+                .into_pointer_value();
+            context.build_store(stack_pointer, value);
+        }
+        _ => {}
+    }
+
+    let block = context
+        .function()
+        .evm()
+        .find_block(&call.return_key, &call.return_stack_hash)?;
+    context.build_unconditional_branch(block.inner);
+
+    Ok(None)
+}
+
+///
+/// Translates the internal function return.
+///
+/// The `output_size` values at the bottom of the function stack frame are written to the
+/// function return pointer.
+///
+pub fn r#return<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    output_size: usize,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    match context.function().r#return.clone() {
+        Some(compiler_llvm_context::FunctionReturn::Primitive { pointer }) => {
+            let stack_pointer = context.evm().stack[0].to_llvm().into_pointer_value();
+            let value = context.build_load(stack_pointer, "return_value");
+            context.build_store(pointer, value);
+        }
+        Some(compiler_llvm_context::FunctionReturn::Compound { pointer, .. }) => {
+            for index in 0..output_size {
+                let stack_pointer = context.evm().stack[index].to_llvm().into_pointer_value();
+                let value = context.build_load(stack_pointer, format!("return_{}", index).as_str());
+                let pointer = unsafe {
+                    context.builder().build_gep(
+                        pointer,
+                        &[
+                            context.field_const(0),
+                            context
+                                .integer_type(compiler_common::BITLENGTH_X32)
+                                .const_int(index as u64, false),
+                        ],
+                        format!("return_{}_gep_pointer", index).as_str(),
+                    )
+                };
+                let pointer = context.builder().build_pointer_cast(
+                    pointer,
+                    context
+                        .field_type()
+                        .ptr_type(compiler_llvm_context::AddressSpace::Stack.into()),
+                    format!("return_{}_gep_pointer_field", index).as_str(),
+                );
+                context.build_store(pointer, value);
+            }
+        }
+        _ => {}
+    }
+    context.build_unconditional_branch(context.function().return_block);
+
+    Ok(None)
+}
@@ -7,11 +7,17 @@ pub mod instruction;
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Instant;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::diagnostic::Diagnostic;
 use crate::evm::ethereal_ir::entry_link::EntryLink;
+use crate::evm::ethereal_ir::statistics::Statistics as EtherealIRStatistics;
 use crate::evm::ethereal_ir::EtherealIR;
 
 use self::data::Data;
@@ -39,6 +45,18 @@ pub struct Assembly {
     /// The factory dependency paths.
     #[serde(skip)]
     pub factory_dependencies: HashSet<String>,
+    /// The Ethereal IR statistics, which are set during the LLVM IR generation.
+    #[serde(skip)]
+    pub ethereal_ir_statistics: Arc<Mutex<EtherealIRStatistics>>,
+    /// The Ethereal IR warnings, which are set during the LLVM IR generation.
+    #[serde(skip)]
+    pub ethereal_ir_warnings: Arc<Mutex<Vec<Diagnostic>>>,
+    /// The Ethereal IR block clone limit. Defaults to `EtherealIR::CLONE_LIMIT_DEFAULT`.
+    #[serde(skip)]
+    pub ethereal_ir_clone_limit: Option<usize>,
+    /// The directory to write the Ethereal IR control-flow graphs to.
+    #[serde(skip)]
+    pub ethereal_ir_dot_directory: Option<PathBuf>,
 }
 
 impl Assembly {
@@ -200,6 +218,7 @@ where
         if context.has_dump_flag(compiler_llvm_context::DumpFlag::EVM) {
             println!("Contract `{}` deploy EVM:\n\n{}", full_path, self);
         }
+        let ethereal_ir_start = Instant::now();
         let deploy_code_blocks = EtherealIR::get_blocks(
             context.evm().version.to_owned(),
             compiler_llvm_context::CodeType::Deploy,
@@ -235,10 +254,21 @@ where
 
         let mut blocks = deploy_code_blocks;
         blocks.extend(runtime_code_blocks);
-        let mut ethereal_ir = EtherealIR::new(context.evm().version.to_owned(), blocks)?;
+        let mut ethereal_ir = EtherealIR::new(
+            context.evm().version.to_owned(),
+            blocks,
+            self.ethereal_ir_clone_limit
+                .unwrap_or(EtherealIR::CLONE_LIMIT_DEFAULT),
+        )?;
+        *self.ethereal_ir_statistics.lock().expect("Sync") =
+            EtherealIRStatistics::new(ethereal_ir_start.elapsed(), ethereal_ir.blocks_cloned());
+        *self.ethereal_ir_warnings.lock().expect("Sync") = ethereal_ir.warnings();
         if context.has_dump_flag(compiler_llvm_context::DumpFlag::EthIR) {
             println!("Contract `{}` Ethereal IR:\n\n{}", full_path, ethereal_ir);
         }
+        if let Some(directory) = self.ethereal_ir_dot_directory.as_deref() {
+            ethereal_ir.write_dot(directory, full_path.as_str())?;
+        }
         ethereal_ir.declare(context)?;
         ethereal_ir.into_llvm(context)?;
 
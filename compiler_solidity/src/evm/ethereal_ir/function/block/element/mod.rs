@@ -9,7 +9,9 @@ use inkwell::values::BasicValue;
 use crate::evm::assembly::instruction::codecopy;
 use crate::evm::assembly::instruction::name::Name as InstructionName;
 use crate::evm::assembly::instruction::Instruction;
+use crate::evm::ethereal_ir::function::call::Call;
 
+use self::stack::element::Element as StackElement;
 use self::stack::Stack;
 
 ///
@@ -23,6 +25,8 @@ pub struct Element {
     pub instruction: Instruction,
     /// The stack data.
     pub stack: Stack,
+    /// The internal function call, if the instruction is a recognized `JUMP [in]`.
+    pub call: Option<Call>,
 }
 
 impl Element {
@@ -34,6 +38,7 @@ impl Element {
             solc_version,
             instruction,
             stack: Stack::new(),
+            call: None,
         }
     }
 
@@ -376,13 +381,26 @@ where
                 )
             }
             InstructionName::JUMP => {
-                let destination = self.stack.pop_tag()?;
+                if let Some(call) = self.call.take() {
+                    crate::evm::assembly::instruction::jump::call(
+                        context,
+                        call,
+                        self.stack.elements.len(),
+                    )
+                } else if let Some(StackElement::ReturnAddress) = self.stack.elements.last() {
+                    crate::evm::assembly::instruction::jump::r#return(
+                        context,
+                        self.stack.elements.len() - 1,
+                    )
+                } else {
+                    let destination = self.stack.pop_tag()?;
 
-                crate::evm::assembly::instruction::jump::unconditional(
-                    context,
-                    destination,
-                    self.stack.hash(),
-                )
+                    crate::evm::assembly::instruction::jump::unconditional(
+                        context,
+                        destination,
+                        self.stack.hash(),
+                    )
+                }
             }
             InstructionName::JUMPI => {
                 let destination = self.stack.pop_tag()?;
@@ -1097,6 +1115,9 @@ impl std::fmt::Display for Element {
         if output_size != 0 {
             write!(f, " + {}", output)?;
         }
+        if let Some(ref call) = self.call {
+            write!(f, " => {}", call.name)?;
+        }
         writeln!(f)?;
 
         Ok(())
@@ -5,7 +5,7 @@
 ///
 /// The Ethereal IR block element stack element.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Element {
     /// The unknown runtime value.
     Value,
@@ -15,6 +15,8 @@ pub enum Element {
     Tag(num::BigUint),
     /// The known compile-time path.
     Path(String),
+    /// The return address of the recovered internal function.
+    ReturnAddress,
 }
 
 impl std::fmt::Display for Element {
@@ -24,6 +26,7 @@ impl std::fmt::Display for Element {
             Self::Constant(value) => write!(f, "{}", value),
             Self::Tag(tag) => write!(f, "TAG_{}", tag),
             Self::Path(path) => write!(f, "{}", path),
+            Self::ReturnAddress => write!(f, "RETURN_ADDRESS"),
         }
     }
 }
@@ -78,6 +78,16 @@ impl Stack {
         }
     }
 
+    ///
+    /// Converts the constant on the top into a tag, since it is consumed as a jump destination.
+    ///
+    pub fn promote_tag(&mut self) {
+        if let Some(Element::Constant(value)) = self.elements.last() {
+            let tag = Element::Tag(value.to_owned());
+            *self.elements.last_mut().expect("Always exists") = tag;
+        }
+    }
+
     ///
     /// Swaps two stack elements.
     ///
@@ -31,6 +31,8 @@ pub struct Block {
     pub initial_stack: ElementStack,
     /// The stack.
     pub stack: ElementStack,
+    /// The key of the block following this one in the instruction sequence.
+    pub next_key: Option<compiler_llvm_context::FunctionBlockKey>,
 }
 
 impl Block {
@@ -70,6 +72,7 @@ impl Block {
             predecessors: HashSet::with_capacity(Self::PREDECESSORS_HASHSET_DEFAULT_CAPACITY),
             initial_stack: ElementStack::new(),
             stack: ElementStack::new(),
+            next_key: None,
         };
 
         while cursor < slice.len() {
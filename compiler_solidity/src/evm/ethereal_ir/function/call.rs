@@ -0,0 +1,47 @@
+//!
+//! The Ethereal IR internal function call.
+//!
+
+///
+/// The Ethereal IR internal function call.
+///
+/// Describes a `JUMP [in]` which has been recognized as a call to a recovered internal function.
+///
+#[derive(Debug, Clone)]
+pub struct Call {
+    /// The callee function name.
+    pub name: String,
+    /// The callee entry block key.
+    pub entry_key: compiler_llvm_context::FunctionBlockKey,
+    /// The number of arguments, excluding the return address.
+    pub input_size: usize,
+    /// The number of return values.
+    pub output_size: usize,
+    /// The return address block key.
+    pub return_key: compiler_llvm_context::FunctionBlockKey,
+    /// The return address block initial stack hash.
+    pub return_stack_hash: md5::Digest,
+}
+
+impl Call {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+        output_size: usize,
+        return_key: compiler_llvm_context::FunctionBlockKey,
+        return_stack_hash: md5::Digest,
+    ) -> Self {
+        Self {
+            name,
+            entry_key,
+            input_size,
+            output_size,
+            return_key,
+            return_stack_hash,
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//!
+//! The Ethereal IR constant folding.
+//!
+
+use num::Integer;
+use num::One;
+use num::Signed;
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::evm::assembly::instruction::name::Name as InstructionName;
+use crate::evm::ethereal_ir::function::block::element::stack::element::Element;
+
+/// The EVM word bit-length.
+pub const WORD_BITLENGTH: usize = 256;
+
+///
+/// Checks whether the instruction is pure, that is, its output only depends on its operands.
+///
+pub fn is_pure(name: InstructionName) -> bool {
+    matches!(
+        name,
+        InstructionName::ADD
+            | InstructionName::MUL
+            | InstructionName::SUB
+            | InstructionName::DIV
+            | InstructionName::SDIV
+            | InstructionName::MOD
+            | InstructionName::SMOD
+            | InstructionName::ADDMOD
+            | InstructionName::MULMOD
+            | InstructionName::EXP
+            | InstructionName::SIGNEXTEND
+            | InstructionName::LT
+            | InstructionName::GT
+            | InstructionName::SLT
+            | InstructionName::SGT
+            | InstructionName::EQ
+            | InstructionName::ISZERO
+            | InstructionName::AND
+            | InstructionName::OR
+            | InstructionName::XOR
+            | InstructionName::NOT
+            | InstructionName::BYTE
+            | InstructionName::SHL
+            | InstructionName::SHR
+            | InstructionName::SAR
+    )
+}
+
+///
+/// Checks whether the instruction is bitwise, that is, it may be used to mask a jump destination.
+///
+pub fn is_bitwise(name: InstructionName) -> bool {
+    matches!(
+        name,
+        InstructionName::AND | InstructionName::OR | InstructionName::XOR
+    )
+}
+
+///
+/// Folds the pure instruction, whose `operands` are ordered from the stack top.
+///
+/// The constants are folded according to the EVM semantics. The tags are folded as numbers,
+/// so the jump destinations computed with arithmetic remain tags, except for the comparisons,
+/// since the tags are not byte offsets and cannot be compared reliably.
+///
+/// The bitwise results of constants remain constants, so they can be compared afterwards. They
+/// are only promoted to tags when consumed by a jump right away.
+///
+/// Returns an unknown value if any operand is not known at compile time.
+///
+pub fn fold(name: InstructionName, operands: &[Element]) -> Element {
+    let mut is_tag = false;
+    let mut values = Vec::with_capacity(operands.len());
+    for operand in operands.iter() {
+        match operand {
+            Element::Constant(value) => values.push(value),
+            Element::Tag(tag) => {
+                is_tag = true;
+                values.push(tag);
+            }
+            _ => return Element::Value,
+        }
+    }
+
+    let result = match (name, values.as_slice()) {
+        (InstructionName::ADD, [a, b]) => wrap(*a + *b),
+        (InstructionName::MUL, [a, b]) => wrap(*a * *b),
+        (InstructionName::SUB, [a, b]) => wrap(*a + modulus() - *b),
+        (InstructionName::DIV, [_a, b]) if b.is_zero() => num::BigUint::zero(),
+        (InstructionName::DIV, [a, b]) => *a / *b,
+        (InstructionName::SDIV, [_a, b]) if b.is_zero() => num::BigUint::zero(),
+        (InstructionName::SDIV, [a, b]) => from_signed(to_signed(a) / to_signed(b)),
+        (InstructionName::MOD, [_a, b]) if b.is_zero() => num::BigUint::zero(),
+        (InstructionName::MOD, [a, b]) => *a % *b,
+        (InstructionName::SMOD, [_a, b]) if b.is_zero() => num::BigUint::zero(),
+        (InstructionName::SMOD, [a, b]) => from_signed(to_signed(a) % to_signed(b)),
+        (InstructionName::ADDMOD, [_a, _b, n]) if n.is_zero() => num::BigUint::zero(),
+        (InstructionName::ADDMOD, [a, b, n]) => (*a + *b) % *n,
+        (InstructionName::MULMOD, [_a, _b, n]) if n.is_zero() => num::BigUint::zero(),
+        (InstructionName::MULMOD, [a, b, n]) => (*a * *b) % *n,
+        (InstructionName::EXP, [a, b]) => a.modpow(b, &modulus()),
+        (InstructionName::SIGNEXTEND, [b, x]) => sign_extend(b, x),
+        (InstructionName::AND, [a, b]) => *a & *b,
+        (InstructionName::OR, [a, b]) => *a | *b,
+        (InstructionName::XOR, [a, b]) => *a ^ *b,
+        (InstructionName::NOT, [a]) => max() - *a,
+        (InstructionName::BYTE, [i, x]) => match i.to_usize() {
+            Some(i) if i < WORD_BITLENGTH / 8 => {
+                (*x >> (WORD_BITLENGTH - 8 * (i + 1))) & num::BigUint::from(u8::MAX)
+            }
+            _ => num::BigUint::zero(),
+        },
+        (InstructionName::SHL, [shift, value]) => match shift.to_usize() {
+            Some(shift) if shift < WORD_BITLENGTH => wrap(*value << shift),
+            _ => num::BigUint::zero(),
+        },
+        (InstructionName::SHR, [shift, value]) => match shift.to_usize() {
+            Some(shift) if shift < WORD_BITLENGTH => *value >> shift,
+            _ => num::BigUint::zero(),
+        },
+        (InstructionName::SAR, [shift, value]) => {
+            let is_negative = to_signed(value).is_negative();
+            let shift = shift
+                .to_usize()
+                .unwrap_or(WORD_BITLENGTH)
+                .min(WORD_BITLENGTH);
+            if is_negative {
+                max() - ((max() - *value) >> shift)
+            } else {
+                *value >> shift
+            }
+        }
+
+        _ if is_tag => return Element::Value,
+        (InstructionName::LT, [a, b]) => from_bool(a < b),
+        (InstructionName::GT, [a, b]) => from_bool(a > b),
+        (InstructionName::SLT, [a, b]) => from_bool(to_signed(a) < to_signed(b)),
+        (InstructionName::SGT, [a, b]) => from_bool(to_signed(a) > to_signed(b)),
+        (InstructionName::EQ, [a, b]) => from_bool(a == b),
+        (InstructionName::ISZERO, [a]) => from_bool(a.is_zero()),
+
+        _ => return Element::Value,
+    };
+
+    if is_tag {
+        Element::Tag(result)
+    } else {
+        Element::Constant(result)
+    }
+}
+
+///
+/// Returns 2^256.
+///
+fn modulus() -> num::BigUint {
+    num::BigUint::one() << WORD_BITLENGTH
+}
+
+///
+/// Returns 2^256 - 1.
+///
+fn max() -> num::BigUint {
+    modulus() - num::BigUint::one()
+}
+
+///
+/// Wraps the value around 2^256.
+///
+fn wrap(value: num::BigUint) -> num::BigUint {
+    value.mod_floor(&modulus())
+}
+
+///
+/// Interprets the word as a two's complement signed integer.
+///
+fn to_signed(value: &num::BigUint) -> num::BigInt {
+    if value.bit((WORD_BITLENGTH - 1) as u64) {
+        num::BigInt::from(value.to_owned()) - num::BigInt::from(modulus())
+    } else {
+        num::BigInt::from(value.to_owned())
+    }
+}
+
+///
+/// Converts the signed integer into a two's complement word.
+///
+fn from_signed(value: num::BigInt) -> num::BigUint {
+    value
+        .mod_floor(&num::BigInt::from(modulus()))
+        .to_biguint()
+        .expect("Always non-negative")
+}
+
+///
+/// Converts the boolean into a word.
+///
+fn from_bool(value: bool) -> num::BigUint {
+    if value {
+        num::BigUint::one()
+    } else {
+        num::BigUint::zero()
+    }
+}
+
+///
+/// Extends the sign of the `x` lowest `b + 1` bytes.
+///
+fn sign_extend(b: &num::BigUint, x: &num::BigUint) -> num::BigUint {
+    let b = match b.to_usize() {
+        Some(b) if b < WORD_BITLENGTH / 8 - 1 => b,
+        _ => return x.to_owned(),
+    };
+
+    let bit = 8 * b + 7;
+    let mask = (num::BigUint::one() << (bit + 1)) - num::BigUint::one();
+    if x.bit(bit as u64) {
+        x | (max() - &mask)
+    } else {
+        x & mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::One;
+    use num::Zero;
+
+    use crate::evm::assembly::instruction::name::Name as InstructionName;
+    use crate::evm::ethereal_ir::function::block::element::stack::element::Element;
+
+    fn constant(value: u64) -> Element {
+        Element::Constant(num::BigUint::from(value))
+    }
+
+    fn negative(value: u64) -> Element {
+        Element::Constant(super::max() + num::BigUint::one() - num::BigUint::from(value))
+    }
+
+    fn power_of_two(exponent: usize) -> Element {
+        Element::Constant(num::BigUint::one() << exponent)
+    }
+
+    #[test]
+    fn sdiv_min_by_minus_one() {
+        assert_eq!(
+            super::fold(
+                InstructionName::SDIV,
+                &[power_of_two(super::WORD_BITLENGTH - 1), negative(1)]
+            ),
+            power_of_two(super::WORD_BITLENGTH - 1)
+        );
+    }
+
+    #[test]
+    fn sdiv_negative() {
+        assert_eq!(
+            super::fold(InstructionName::SDIV, &[negative(7), constant(2)]),
+            negative(3)
+        );
+    }
+
+    #[test]
+    fn smod_takes_dividend_sign() {
+        assert_eq!(
+            super::fold(InstructionName::SMOD, &[negative(7), constant(3)]),
+            negative(1)
+        );
+        assert_eq!(
+            super::fold(InstructionName::SMOD, &[constant(7), negative(3)]),
+            constant(1)
+        );
+    }
+
+    #[test]
+    fn sar_negative() {
+        assert_eq!(
+            super::fold(InstructionName::SAR, &[constant(1), negative(3)]),
+            negative(2)
+        );
+        assert_eq!(
+            super::fold(InstructionName::SAR, &[constant(4), negative(1)]),
+            negative(1)
+        );
+    }
+
+    #[test]
+    fn sar_shift_overflow() {
+        assert_eq!(
+            super::fold(InstructionName::SAR, &[constant(256), negative(3)]),
+            negative(1)
+        );
+        assert_eq!(
+            super::fold(InstructionName::SAR, &[power_of_two(64), negative(3)]),
+            negative(1)
+        );
+        assert_eq!(
+            super::fold(InstructionName::SAR, &[constant(256), constant(3)]),
+            constant(0)
+        );
+    }
+
+    #[test]
+    fn signextend_large_byte_index() {
+        let value = Element::Constant(super::max() - num::BigUint::from(0xffu64));
+        for b in [31, 32, 1000] {
+            assert_eq!(
+                super::fold(InstructionName::SIGNEXTEND, &[constant(b), value.clone()]),
+                value
+            );
+        }
+        assert_eq!(
+            super::fold(
+                InstructionName::SIGNEXTEND,
+                &[power_of_two(128), value.clone()]
+            ),
+            value
+        );
+    }
+
+    #[test]
+    fn signextend() {
+        assert_eq!(
+            super::fold(InstructionName::SIGNEXTEND, &[constant(0), constant(0xff)]),
+            negative(1)
+        );
+        assert_eq!(
+            super::fold(InstructionName::SIGNEXTEND, &[constant(0), constant(0x17f)]),
+            constant(0x7f)
+        );
+    }
+
+    #[test]
+    fn byte_out_of_range() {
+        assert_eq!(
+            super::fold(InstructionName::BYTE, &[constant(31), constant(0xab)]),
+            constant(0xab)
+        );
+        assert_eq!(
+            super::fold(InstructionName::BYTE, &[constant(32), constant(0xab)]),
+            constant(0)
+        );
+        assert_eq!(
+            super::fold(InstructionName::BYTE, &[power_of_two(128), negative(1)]),
+            constant(0)
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        for name in [
+            InstructionName::DIV,
+            InstructionName::SDIV,
+            InstructionName::MOD,
+            InstructionName::SMOD,
+        ] {
+            assert_eq!(
+                super::fold(name, &[constant(7), constant(0)]),
+                Element::Constant(num::BigUint::zero())
+            );
+        }
+        for name in [InstructionName::ADDMOD, InstructionName::MULMOD] {
+            assert_eq!(
+                super::fold(name, &[constant(7), constant(5), constant(0)]),
+                Element::Constant(num::BigUint::zero())
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise() {
+        assert_eq!(
+            super::fold(InstructionName::AND, &[constant(0x1234), constant(0xff)]),
+            constant(0x34)
+        );
+        assert_eq!(
+            super::fold(InstructionName::OR, &[constant(0x1200), constant(0x34)]),
+            constant(0x1234)
+        );
+        assert_eq!(
+            super::fold(InstructionName::XOR, &[constant(0xff), constant(0x0f)]),
+            constant(0xf0)
+        );
+        assert_eq!(
+            super::fold(
+                InstructionName::AND,
+                &[
+                    Element::Tag(num::BigUint::from(0x1234u64)),
+                    constant(0xffff_ffff)
+                ]
+            ),
+            Element::Tag(num::BigUint::from(0x1234u64))
+        );
+    }
+
+    #[test]
+    fn masked_constant_comparison() {
+        let masked = super::fold(InstructionName::AND, &[negative(1), constant(0xffff_ffff)]);
+        assert_eq!(
+            super::fold(
+                InstructionName::LT,
+                &[masked.clone(), constant(0x1_0000_0000)]
+            ),
+            constant(1)
+        );
+        assert_eq!(
+            super::fold(
+                InstructionName::EQ,
+                &[masked.clone(), constant(0xffff_ffff)]
+            ),
+            constant(1)
+        );
+        assert_eq!(super::fold(InstructionName::ISZERO, &[masked]), constant(0));
+    }
+}
@@ -3,23 +3,26 @@
 //!
 
 pub mod block;
+pub mod call;
+pub mod folding;
 pub mod queue_element;
+pub mod registry;
+pub mod truncation;
 pub mod visited_element;
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::fmt::Write;
 use std::ops::BitAnd;
 
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
-use num::CheckedAdd;
-use num::CheckedMul;
-use num::CheckedSub;
 use num::Num;
-use num::ToPrimitive;
 use num::Zero;
 
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::Diagnostic;
 use crate::evm::assembly::instruction::name::Name as InstructionName;
 use crate::evm::assembly::instruction::Instruction;
 use crate::evm::ethereal_ir::function::block::element::stack::element::Element;
@@ -29,7 +32,10 @@ use crate::evm::ethereal_ir::EtherealIR;
 use self::block::element::stack::element::Element as StackElement;
 use self::block::element::Element as BlockElement;
 use self::block::Block;
+use self::call::Call;
 use self::queue_element::QueueElement;
+use self::registry::Registry;
+use self::truncation::Truncation;
 use self::visited_element::VisitedElement;
 
 ///
@@ -39,73 +45,159 @@ use self::visited_element::VisitedElement;
 pub struct Function {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
+    /// The function name.
+    pub name: String,
+    /// The entry block key and initial stack hash, if the function is a recovered internal one.
+    pub entry: Option<VisitedElement>,
+    /// The number of arguments, excluding the return address.
+    pub input_size: usize,
+    /// The number of return values, which is known after the first return has been reached.
+    pub output_size: Option<usize>,
     /// The separately labelled blocks.
     pub blocks: BTreeMap<compiler_llvm_context::FunctionBlockKey, Vec<Block>>,
     /// The function stack size.
     pub stack_size: usize,
+    /// The blocks truncated to `INVALID` by the stack simulation.
+    pub truncations: Vec<Truncation>,
 }
 
 impl Function {
+    /// The `JUMP` annotation of the internal function calls.
+    pub const JUMP_INTO_FUNCTION: &'static str = "[in]";
+
     ///
     /// A shortcut constructor.
     ///
+    /// Builds the entry function, recovering the internal functions into the `registry`.
+    ///
     pub fn new(
         solc_version: semver::Version,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
-        visited: &mut HashSet<VisitedElement>,
+        registry: &mut Registry,
     ) -> anyhow::Result<Self> {
         let mut function = Self {
             solc_version,
+            name: EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME.to_owned(),
+            entry: None,
+            input_size: 0,
+            output_size: None,
             blocks: BTreeMap::new(),
             stack_size: 0,
+            truncations: vec![],
         };
-        function.consume_block(
+        function.consume(
             blocks,
-            visited,
-            QueueElement::new(
-                compiler_llvm_context::FunctionBlockKey::new(
-                    compiler_llvm_context::CodeType::Deploy,
-                    num::BigUint::zero(),
+            registry,
+            vec![
+                QueueElement::new(
+                    compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Deploy,
+                        num::BigUint::zero(),
+                    ),
+                    None,
+                    Stack::new(),
                 ),
-                None,
-                Stack::new(),
-            ),
-        )?;
-        function.consume_block(
-            blocks,
-            visited,
-            QueueElement::new(
-                compiler_llvm_context::FunctionBlockKey::new(
-                    compiler_llvm_context::CodeType::Runtime,
-                    num::BigUint::zero(),
+                QueueElement::new(
+                    compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Runtime,
+                        num::BigUint::zero(),
+                    ),
+                    None,
+                    Stack::new(),
                 ),
-                None,
-                Stack::new(),
-            ),
+            ],
         )?;
         Ok(function.finalize())
     }
 
+    ///
+    /// Tries to recover the internal function starting at the `key` block.
+    ///
+    /// The `stack` is the callee frame, where the return address is followed by the arguments.
+    /// Unlike the entry function, any invalid stack state is an error here, since it usually
+    /// means that the code accesses the caller frame and cannot be separated.
+    ///
+    pub fn try_new_internal(
+        solc_version: semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+        name: String,
+        key: compiler_llvm_context::FunctionBlockKey,
+        stack: Stack,
+    ) -> anyhow::Result<Self> {
+        let mut function = Self {
+            solc_version,
+            name,
+            entry: Some(VisitedElement::new(key.clone(), stack.hash())),
+            input_size: stack.elements.len().saturating_sub(1),
+            output_size: None,
+            blocks: BTreeMap::new(),
+            stack_size: 0,
+            truncations: vec![],
+        };
+        function.consume(blocks, registry, vec![QueueElement::new(key, None, stack)])?;
+        if function.output_size.is_none() {
+            anyhow::bail!("Function `{}` never returns", function.name);
+        }
+        Ok(function.finalize())
+    }
+
+    ///
+    /// Consumes the blocks reachable from the `entries` in the depth-first order.
+    ///
+    /// The worklist is used instead of recursion, so the deeply nested control flow cannot
+    /// overflow the native stack.
+    ///
+    fn consume(
+        &mut self,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+        mut entries: Vec<QueueElement>,
+    ) -> anyhow::Result<()> {
+        let mut visited = HashSet::with_capacity(blocks.len());
+        entries.reverse();
+        let mut worklist = entries;
+        while let Some(queue_element) = worklist.pop() {
+            let queue = self.consume_block(blocks, &mut visited, registry, queue_element)?;
+            worklist.extend(queue.into_iter().rev());
+        }
+
+        Ok(())
+    }
+
     ///
     /// Consumes the entry or a conditional block attached to another one.
     ///
+    /// Returns the successors to be consumed next.
+    ///
     fn consume_block(
         &mut self,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
         visited: &mut HashSet<VisitedElement>,
+        registry: &mut Registry,
         mut queue_element: QueueElement,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<QueueElement>> {
         let version = self.solc_version.to_owned();
+        let is_internal = self.entry.is_some();
 
         let mut queue = vec![];
 
         let visited_element =
             VisitedElement::new(queue_element.block_key.clone(), queue_element.stack.hash());
         if visited.contains(&visited_element) {
-            return Ok(());
+            return Ok(queue);
         }
         visited.insert(visited_element);
 
+        let clones = self
+            .blocks
+            .get(&queue_element.block_key)
+            .map(Vec::len)
+            .unwrap_or_default();
+        if clones >= registry.clone_limit {
+            return Err(self.clone_limit_error(registry.clone_limit));
+        }
+
         let mut block = blocks
             .get(&queue_element.block_key)
             .cloned()
@@ -120,19 +212,37 @@ impl Function {
         }
 
         let mut block_size = 0;
+        let mut truncation = None;
+        let mut previous_name = None;
         for block_element in block.elements.iter_mut() {
             block_size += 1;
 
-            if Self::handle_instruction(
+            if matches!(
+                block_element.instruction.name,
+                InstructionName::JUMP | InstructionName::JUMPI
+            ) && previous_name.map(folding::is_bitwise).unwrap_or_default()
+            {
+                block.stack.promote_tag();
+            }
+            previous_name = Some(block_element.instruction.name);
+
+            if let Err(error) = Self::handle_instruction(
                 block.key.code_type,
                 &mut block.stack,
                 block_element,
                 &version,
                 &mut queue,
                 &mut queue_element,
-            )
-            .is_err()
-            {
+            ) {
+                if is_internal {
+                    return Err(error);
+                }
+                truncation = Some(Truncation::new(
+                    block.key.clone(),
+                    block_size - 1,
+                    block_element.instruction.to_string().trim().to_owned(),
+                    error.to_string(),
+                ));
                 block_element.stack = block.stack.clone();
                 block_element.instruction = Instruction::invalid();
                 break;
@@ -140,13 +250,178 @@ impl Function {
         }
         block.elements.truncate(block_size);
 
-        for element in queue.into_iter() {
-            self.consume_block(blocks, visited, element)?;
+        let block_key = block.key.clone();
+        let next_key = block.next_key.clone();
+        let jump = block
+            .elements
+            .last()
+            .filter(|element| element.instruction.name == InstructionName::JUMP)
+            .map(|element| (element.instruction.value.clone(), element.stack.clone()));
+        if let Some(truncation) = truncation {
+            self.truncations.push(truncation);
+        }
+        if let Some((value, stack)) = jump {
+            if let Some(StackElement::ReturnAddress) = stack.elements.last() {
+                self.handle_return(stack)?;
+            } else if value.as_deref() == Some(Self::JUMP_INTO_FUNCTION) {
+                if let Some((call, return_stack)) =
+                    self.recover_call(blocks, registry, &block_key, next_key, stack)
+                {
+                    queue.pop();
+                    queue.push(QueueElement::new(
+                        call.return_key.clone(),
+                        Some(block_key.clone()),
+                        return_stack,
+                    ));
+                    if let Some(element) = self
+                        .blocks
+                        .get_mut(&block_key)
+                        .and_then(|blocks| blocks.last_mut())
+                        .and_then(|block| block.elements.last_mut())
+                    {
+                        element.call = Some(call);
+                    }
+                }
+            }
+        }
+
+        Ok(queue)
+    }
+
+    ///
+    /// Returns the error listing the tags, whose blocks have reached the clone `limit`.
+    ///
+    fn clone_limit_error(&self, limit: usize) -> anyhow::Error {
+        let tags: Vec<String> = self
+            .blocks
+            .iter()
+            .filter(|(_key, blocks)| blocks.len() >= limit)
+            .map(|(key, blocks)| format!("{} ({} clones)", key, blocks.len()))
+            .collect();
+        Diagnostic::new_error(
+            DiagnosticCode::EthIRCloneLimit,
+            format!(
+                "Function `{}` exceeds the block clone limit of {} for tag(s): {}. \
+                 The limit can be raised with `--ethir-clone-limit`.",
+                self.name,
+                limit,
+                tags.join(", ")
+            ),
+        )
+        .into()
+    }
+
+    ///
+    /// Checks the return of a recovered internal function, where `stack` is the state before
+    /// the `JUMP` to the return address.
+    ///
+    /// All the returns of a function must leave the same number of values, and none of them may
+    /// be a tag, since the values returned from a call are not known at compile time.
+    ///
+    fn handle_return(&mut self, mut stack: Stack) -> anyhow::Result<()> {
+        if self.entry.is_none() {
+            anyhow::bail!("Unexpected return address in function `{}`", self.name);
+        }
+
+        stack.pop()?;
+        if let Some(element) = stack
+            .elements
+            .iter()
+            .find(|element| matches!(element, StackElement::Tag(_) | StackElement::ReturnAddress))
+        {
+            anyhow::bail!("Function `{}` returns {}", self.name, element);
+        }
+
+        let output_size = stack.elements.len();
+        match self.output_size {
+            Some(expected) if expected != output_size => anyhow::bail!(
+                "Function `{}` returns either {} or {} values",
+                self.name,
+                expected,
+                output_size
+            ),
+            _ => self.output_size = Some(output_size),
         }
 
         Ok(())
     }
 
+    ///
+    /// Tries to represent a `JUMP [in]` as a call to a separate function.
+    ///
+    /// `stack` is the state before the `JUMP`. The return address is the tag of the block
+    /// following the `JUMP`, and the elements above its topmost occurrence are the arguments.
+    ///
+    /// Returns the call and the stack state after the callee returns, or `None`, if the call
+    /// must be inlined.
+    ///
+    fn recover_call(
+        &self,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        registry: &mut Registry,
+        block_key: &compiler_llvm_context::FunctionBlockKey,
+        next_key: Option<compiler_llvm_context::FunctionBlockKey>,
+        mut stack: Stack,
+    ) -> Option<(Call, Stack)> {
+        let callee_key = match stack.elements.pop()? {
+            StackElement::Tag(destination) => {
+                Self::destination_key(block_key.code_type, destination)
+            }
+            _ => return None,
+        };
+        let return_key = next_key?;
+        let position = stack.elements.iter().rposition(
+            |element| matches!(element, StackElement::Tag(tag) if *tag == return_key.tag),
+        )?;
+        let input_size = stack.elements.len() - position - 1;
+
+        let mut callee_stack = Stack::new();
+        callee_stack.push(StackElement::ReturnAddress);
+        for element in stack.elements[position + 1..].iter() {
+            callee_stack.push(match element {
+                StackElement::Tag(tag) => StackElement::Tag(tag.to_owned()),
+                StackElement::ReturnAddress => return None,
+                _ => StackElement::Value,
+            });
+        }
+
+        let (name, output_size) =
+            registry.recover(&self.solc_version, blocks, callee_key.clone(), callee_stack)?;
+
+        let mut return_stack = Stack::new_with_elements(stack.elements[..position].to_vec());
+        for _ in 0..output_size {
+            return_stack.push(StackElement::Value);
+        }
+        let call = Call::new(
+            name,
+            callee_key,
+            input_size,
+            output_size,
+            return_key,
+            return_stack.hash(),
+        );
+        Some((call, return_stack))
+    }
+
+    ///
+    /// Returns the key of the block the `destination` tag points to.
+    ///
+    /// The tags above `u32::MAX` point to the runtime code from the deploy code.
+    ///
+    fn destination_key(
+        code_type: compiler_llvm_context::CodeType,
+        destination: num::BigUint,
+    ) -> compiler_llvm_context::FunctionBlockKey {
+        if destination > num::BigUint::from(u32::MAX) {
+            compiler_llvm_context::FunctionBlockKey::new(
+                compiler_llvm_context::CodeType::Runtime,
+                destination - num::BigUint::from(1u64 << 32),
+            )
+        } else {
+            compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
+        }
+    }
+
     ///
     /// Processes an instruction, returning an error, if there is an invalid stack state.
     ///
@@ -178,15 +453,12 @@ impl Function {
                 queue_element.predecessor = Some(queue_element.block_key.clone());
 
                 block_element.stack = block_stack.clone();
+                if let Some(StackElement::ReturnAddress) = block_stack.elements.last() {
+                    block_stack.pop()?;
+                    return Ok(());
+                }
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    compiler_llvm_context::FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::destination_key(code_type, destination);
                 queue.push(QueueElement::new(
                     block_key,
                     queue_element.predecessor.clone(),
@@ -201,14 +473,7 @@ impl Function {
 
                 block_element.stack = block_stack.clone();
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    compiler_llvm_context::FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::destination_key(code_type, destination);
                 block_stack.pop()?;
                 queue.push(QueueElement::new(
                     block_key,
@@ -513,206 +778,24 @@ impl Function {
                 block_element.stack = block_stack.clone();
             }
 
-            ref instruction @ Instruction {
-                name: InstructionName::ADD,
-                ..
-            } => {
-                let operand_2 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_1 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        match operand_1.checked_add(operand_2) {
-                            Some(result) => Element::Constant(result),
-                            None => Element::Value,
-                        }
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::SUB,
-                ..
-            } => {
-                let operand_2 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_1 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        match operand_1.checked_sub(operand_2) {
-                            Some(result) => Element::Constant(result),
-                            None => Element::Value,
-                        }
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::MUL,
-                ..
-            } => {
-                let operand_2 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_1 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        match operand_1.checked_mul(operand_2) {
-                            Some(result) => Element::Constant(result),
-                            None => Element::Value,
-                        }
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::SHL,
-                ..
-            } => {
-                let value = block_stack.elements.get(block_stack.elements.len() - 2);
-                let offset = block_stack.elements.last();
-
-                let result = match (value, offset) {
-                    (Some(Element::Tag(tag)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Tag(tag << offset)
-                    }
-                    (Some(Element::Constant(constant)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Constant(constant << offset)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::SHR,
-                ..
-            } => {
-                let value = block_stack.elements.get(block_stack.elements.len() - 2);
-                let offset = block_stack.elements.last();
-
-                let result = match (value, offset) {
-                    (Some(Element::Tag(tag)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Tag(tag >> offset)
-                    }
-                    (Some(Element::Constant(constant)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Constant(constant >> offset)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::OR,
-                ..
-            } => {
-                let operand_1 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_2 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Tag(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Tag(operand_1)), Some(Element::Constant(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        Element::Tag(operand_1 | operand_2)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::XOR,
-                ..
-            } => {
-                let operand_1 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_2 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Tag(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Tag(operand_1)), Some(Element::Constant(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        Element::Tag(operand_1 ^ operand_2)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
+            ref instruction if folding::is_pure(instruction.name) => {
+                let input_size = instruction.input_size(version);
+                if block_stack.elements.len() < input_size {
+                    anyhow::bail!("Stack underflow");
                 }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::AND,
-                ..
-            } => {
-                let operand_1 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_2 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Tag(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Tag(operand_1)), Some(Element::Constant(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        Element::Tag(operand_1 & operand_2)
-                    }
-                    _ => Element::Value,
-                };
+                let operands: Vec<StackElement> = block_stack
+                    .elements
+                    .iter()
+                    .rev()
+                    .take(input_size)
+                    .cloned()
+                    .collect();
+                let result = folding::fold(instruction.name, operands.as_slice());
 
                 block_stack.push(result);
                 block_element.stack = block_stack.clone();
                 let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
+                for _ in 0..input_size {
                     block_stack.pop()?;
                 }
                 block_stack.push(output);
@@ -762,12 +845,158 @@ impl Function {
             .expect("Always exists")
     }
 
+    ///
+    /// Writes the blocks of the `code_type` as a Graphviz cluster into `dot`.
+    ///
+    /// The nodes are labelled with the block key and clone index, and have the initial stack
+    /// states as tooltips. The edges are the jumps, the fallthroughs, and the internal calls.
+    ///
+    pub fn write_dot(
+        &self,
+        code_type: compiler_llvm_context::CodeType,
+        dot: &mut String,
+    ) -> std::fmt::Result {
+        if self.blocks.keys().all(|key| key.code_type != code_type) {
+            return Ok(());
+        }
+
+        writeln!(dot, "    subgraph \"cluster_{}\" {{", self.name)?;
+        writeln!(dot, "        label=\"{}\";", self.name)?;
+        for (key, blocks) in self.blocks.iter() {
+            if key.code_type != code_type {
+                continue;
+            }
+            for (index, block) in blocks.iter().enumerate() {
+                writeln!(
+                    dot,
+                    "        \"{}\" [label=\"{}/{}\", tooltip=\"{}\"];",
+                    Self::dot_node(self.name.as_str(), key, index),
+                    key,
+                    index,
+                    block.initial_stack.to_string().replace('"', "\\\""),
+                )?;
+            }
+        }
+        writeln!(dot, "    }}")?;
+
+        for (key, blocks) in self.blocks.iter() {
+            if key.code_type != code_type {
+                continue;
+            }
+            for (index, block) in blocks.iter().enumerate() {
+                let source = Self::dot_node(self.name.as_str(), key, index);
+                for element in block.elements.iter() {
+                    let mut stack = element.stack.clone();
+                    let (destination, label) = match element.instruction {
+                        Instruction {
+                            name: InstructionName::JUMP,
+                            ..
+                        } => match element.call {
+                            Some(ref call) => {
+                                writeln!(
+                                    dot,
+                                    "    \"{}\" -> \"{}\" [label=\"call\", style=dashed];",
+                                    source,
+                                    Self::dot_node(call.name.as_str(), &call.entry_key, 0),
+                                )?;
+                                (
+                                    self.clone_index(&call.return_key, &call.return_stack_hash)
+                                        .map(|index| (call.return_key.clone(), index)),
+                                    "return",
+                                )
+                            }
+                            None => match stack.pop_tag() {
+                                Ok(tag) => {
+                                    let key = Self::destination_key(key.code_type, tag);
+                                    (
+                                        self.clone_index(&key, &stack.hash())
+                                            .map(|index| (key, index)),
+                                        "JUMP",
+                                    )
+                                }
+                                Err(_) => (None, "JUMP"),
+                            },
+                        },
+                        Instruction {
+                            name: InstructionName::JUMPI,
+                            ..
+                        } => match stack.pop_tag() {
+                            Ok(tag) => {
+                                let key = Self::destination_key(key.code_type, tag);
+                                let _condition = stack.pop();
+                                (
+                                    self.clone_index(&key, &stack.hash())
+                                        .map(|index| (key, index)),
+                                    "JUMPI",
+                                )
+                            }
+                            Err(_) => (None, "JUMPI"),
+                        },
+                        Instruction {
+                            name: InstructionName::Tag,
+                            value: Some(ref tag),
+                        } => {
+                            let tag: num::BigUint = tag.parse().expect("Always valid");
+                            let key =
+                                compiler_llvm_context::FunctionBlockKey::new(key.code_type, tag);
+                            (
+                                self.clone_index(&key, &stack.hash())
+                                    .map(|index| (key, index)),
+                                "fallthrough",
+                            )
+                        }
+                        _ => continue,
+                    };
+                    if let Some((key, index)) = destination {
+                        writeln!(
+                            dot,
+                            "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                            source,
+                            Self::dot_node(self.name.as_str(), &key, index),
+                            label,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the clone index of the `key` block with the `stack_hash` initial stack state.
+    ///
+    fn clone_index(
+        &self,
+        key: &compiler_llvm_context::FunctionBlockKey,
+        stack_hash: &md5::Digest,
+    ) -> Option<usize> {
+        self.blocks
+            .get(key)?
+            .iter()
+            .position(|block| block.initial_stack.hash() == *stack_hash)
+    }
+
+    ///
+    /// Returns the Graphviz node identifier of the block clone.
+    ///
+    fn dot_node(
+        function_name: &str,
+        key: &compiler_llvm_context::FunctionBlockKey,
+        index: usize,
+    ) -> String {
+        format!("{}/{}/{}", function_name, key, index)
+    }
+
     ///
     /// Finalizes the function data.
     ///
     fn finalize(mut self) -> Self {
         for (_tag, blocks) in self.blocks.iter() {
             for block in blocks.iter() {
+                if block.initial_stack.elements.len() > self.stack_size {
+                    self.stack_size = block.initial_stack.elements.len();
+                }
                 for block_element in block.elements.iter() {
                     if block_element.stack.elements.len() > self.stack_size {
                         self.stack_size = block_element.stack.elements.len();
@@ -785,34 +1014,67 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        if self.entry.is_none() {
+            context.add_function_evm(
+                self.name.as_str(),
+                context.void_type().fn_type(
+                    &[context
+                        .integer_type(compiler_common::BITLENGTH_BOOLEAN as usize)
+                        .as_basic_type_enum()
+                        .into()],
+                    false,
+                ),
+                Some(inkwell::module::Linkage::Private),
+                compiler_llvm_context::FunctionEVMData::new(self.stack_size),
+            );
+            return Ok(());
+        }
+
+        let output_size = self.output_size.unwrap_or_default();
+        let function_type = context.function_type(
+            output_size,
+            vec![context.field_type().as_basic_type_enum(); self.input_size],
+        );
         context.add_function_evm(
-            EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
-            context.void_type().fn_type(
-                &[context
-                    .integer_type(compiler_common::BITLENGTH_BOOLEAN as usize)
-                    .as_basic_type_enum()
-                    .into()],
-                false,
-            ),
+            self.name.as_str(),
+            function_type,
             Some(inkwell::module::Linkage::Private),
             compiler_llvm_context::FunctionEVMData::new(self.stack_size),
         );
 
+        if output_size > 1 {
+            let function = context
+                .functions
+                .get(self.name.as_str())
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Undeclared function `{}`", self.name))?;
+            let pointer = function
+                .value
+                .get_first_param()
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Function `{}` return pointer parameter is missing",
+                        self.name
+                    )
+                })?
+                .into_pointer_value();
+            context.set_function(function);
+            context.set_function_return(compiler_llvm_context::FunctionReturn::compound(
+                pointer,
+                output_size,
+            ));
+        }
+
         Ok(())
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         let function = context
             .functions
-            .get(EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME)
+            .get(self.name.as_str())
             .cloned()
-            .expect("Always exists");
-        let is_deploy_code_flag = function
-            .value
-            .get_first_param()
-            .expect("Always exists")
-            .into_int_value();
-        context.set_function(function);
+            .ok_or_else(|| anyhow::anyhow!("Undeclared function `{}`", self.name))?;
+        context.set_function(function.clone());
 
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
@@ -841,25 +1103,78 @@ where
         }
         context.evm_mut().stack = stack_variables;
 
-        let deploy_code_block = context.function().evm().find_block(
-            &compiler_llvm_context::FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Deploy,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        let runtime_code_block = context.function().evm().find_block(
-            &compiler_llvm_context::FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Runtime,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        context.build_conditional_branch(
-            is_deploy_code_flag,
-            deploy_code_block.inner,
-            runtime_code_block.inner,
-        );
+        let r#return = match self.entry {
+            None => {
+                let is_deploy_code_flag = function
+                    .value
+                    .get_first_param()
+                    .expect("Always exists")
+                    .into_int_value();
+                let deploy_code_block = context.function().evm().find_block(
+                    &compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Deploy,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                let runtime_code_block = context.function().evm().find_block(
+                    &compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Runtime,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                context.build_conditional_branch(
+                    is_deploy_code_flag,
+                    deploy_code_block.inner,
+                    runtime_code_block.inner,
+                );
+
+                compiler_llvm_context::FunctionReturn::none()
+            }
+            Some(ref entry) => {
+                let r#return = match function.r#return {
+                    Some(r#return) => r#return,
+                    None if self.output_size == Some(1) => {
+                        let pointer = context.build_alloca(context.field_type(), "return_pointer");
+                        context.build_store(pointer, context.field_const(0));
+                        compiler_llvm_context::FunctionReturn::primitive(pointer)
+                    }
+                    None => compiler_llvm_context::FunctionReturn::none(),
+                };
+                context.set_function_return(r#return.clone());
+
+                let offset = match r#return {
+                    compiler_llvm_context::FunctionReturn::Compound { .. } => 1,
+                    _ => 0,
+                };
+                for index in 0..self.input_size {
+                    let value = context
+                        .function()
+                        .value
+                        .get_nth_param((index + offset) as u32)
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Function `{}` argument {} parameter is missing",
+                                self.name,
+                                index
+                            )
+                        })?;
+                    let pointer = context.evm().stack[index + 1]
+                        .to_llvm()
+                        .into_pointer_value();
+                    context.build_store(pointer, value);
+                }
+
+                let entry_block = context
+                    .function()
+                    .evm()
+                    .find_block(&entry.block_key, &entry.stack_hash)?;
+                context.build_unconditional_branch(entry_block.inner);
+
+                r#return
+            }
+        };
 
         for (key, blocks) in self.blocks.into_iter() {
             for (llvm_block, ir_block) in context
@@ -879,7 +1194,18 @@ where
         }
 
         context.set_basic_block(context.function().return_block);
-        context.build_return(None);
+        match r#return {
+            compiler_llvm_context::FunctionReturn::None => {
+                context.build_return(None);
+            }
+            compiler_llvm_context::FunctionReturn::Primitive { pointer } => {
+                let return_value = context.build_load(pointer, "return_value");
+                context.build_return(Some(&return_value));
+            }
+            compiler_llvm_context::FunctionReturn::Compound { pointer, .. } => {
+                context.build_return(Some(&pointer));
+            }
+        }
 
         Ok(())
     }
@@ -887,7 +1213,17 @@ where
 
 impl std::fmt::Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?;
+        match self.entry {
+            Some(_) => writeln!(
+                f,
+                "function {}({} -> {}) (max_sp = {}) {{",
+                self.name,
+                self.input_size,
+                self.output_size.unwrap_or_default(),
+                self.stack_size,
+            )?,
+            None => writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?,
+        }
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
                 writeln!(
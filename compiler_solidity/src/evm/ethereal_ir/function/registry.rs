@@ -0,0 +1,106 @@
+//!
+//! The Ethereal IR recovered function registry.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::Diagnostic;
+use crate::evm::ethereal_ir::function::block::element::stack::Stack;
+use crate::evm::ethereal_ir::function::block::Block;
+use crate::evm::ethereal_ir::function::visited_element::VisitedElement;
+use crate::evm::ethereal_ir::function::Function;
+
+///
+/// The Ethereal IR recovered function registry.
+///
+/// Each internal function is recovered once for every distinct combination of its entry block
+/// and the tags passed as its arguments.
+///
+#[derive(Debug)]
+pub struct Registry {
+    /// The maximum number of clones of a single block in a function.
+    pub clone_limit: usize,
+    /// The recovered functions in the order of discovery.
+    pub functions: Vec<Function>,
+    /// The warnings about the functions which could not be recovered and have been inlined.
+    pub warnings: Vec<Diagnostic>,
+    /// The entry blocks of the failed functions, so each of them is reported once.
+    failed: HashSet<compiler_llvm_context::FunctionBlockKey>,
+    /// The function indexes, where `None` denotes a function being analyzed or a failed one.
+    indexes: HashMap<VisitedElement, Option<usize>>,
+}
+
+impl Registry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(clone_limit: usize) -> Self {
+        Self {
+            clone_limit,
+            functions: Vec::new(),
+            warnings: Vec::new(),
+            failed: HashSet::new(),
+            indexes: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Tries to recover the function starting at the `key` block with the `stack` initial state.
+    ///
+    /// Returns the function name and the number of its return values, or `None`, if the function
+    /// is recursive or cannot be represented separately, in which case the call must be inlined.
+    /// The reason of the latter is recorded as a warning.
+    ///
+    pub fn recover(
+        &mut self,
+        solc_version: &semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        key: compiler_llvm_context::FunctionBlockKey,
+        stack: Stack,
+    ) -> Option<(String, usize)> {
+        let entry = VisitedElement::new(key.clone(), stack.hash());
+        if let Some(index) = self.indexes.get(&entry) {
+            return index.map(|index| {
+                let function = &self.functions[index];
+                (
+                    function.name.to_owned(),
+                    function.output_size.unwrap_or_default(),
+                )
+            });
+        }
+        self.indexes.insert(entry.clone(), None);
+
+        let name = format!("function_{}_{}", key, self.indexes.len());
+        let function = match Function::try_new_internal(
+            solc_version.to_owned(),
+            blocks,
+            self,
+            name,
+            key.clone(),
+            stack,
+        ) {
+            Ok(function) => function,
+            Err(error) => {
+                if self.failed.insert(key.clone()) {
+                    self.warnings.push(Diagnostic::new_warning(
+                        DiagnosticCode::EthIRRecovery,
+                        format!(
+                            "The function at block {} cannot be recovered and has been inlined: {}",
+                            key, error
+                        ),
+                    ));
+                }
+                return None;
+            }
+        };
+        let result = (
+            function.name.to_owned(),
+            function.output_size.unwrap_or_default(),
+        );
+        self.indexes.insert(entry, Some(self.functions.len()));
+        self.functions.push(function);
+        Some(result)
+    }
+}
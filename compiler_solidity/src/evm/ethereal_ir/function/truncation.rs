@@ -0,0 +1,55 @@
+//!
+//! The Ethereal IR block truncation.
+//!
+
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::Diagnostic;
+
+///
+/// The Ethereal IR block truncation.
+///
+/// Describes an instruction which could not be handled by the stack simulation, and has been
+/// replaced with `INVALID` along with the rest of its block.
+///
+#[derive(Debug, Clone)]
+pub struct Truncation {
+    /// The block key.
+    pub key: compiler_llvm_context::FunctionBlockKey,
+    /// The instruction index in the block.
+    pub index: usize,
+    /// The instruction replaced with `INVALID`.
+    pub instruction: String,
+    /// The stack simulation error.
+    pub reason: String,
+}
+
+impl Truncation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        key: compiler_llvm_context::FunctionBlockKey,
+        index: usize,
+        instruction: String,
+        reason: String,
+    ) -> Self {
+        Self {
+            key,
+            index,
+            instruction,
+            reason,
+        }
+    }
+}
+
+impl From<&Truncation> for Diagnostic {
+    fn from(truncation: &Truncation) -> Self {
+        Diagnostic::new_warning(
+            DiagnosticCode::EthIRTruncation,
+            format!(
+                "Block {} has been truncated at instruction #{} `{}`, which is unreachable or has an invalid stack state ({}). The instruction has been replaced with INVALID, which reverts at runtime",
+                truncation.key, truncation.index, truncation.instruction, truncation.reason
+            ),
+        )
+    }
+}
@@ -4,13 +4,18 @@
 
 pub mod entry_link;
 pub mod function;
+pub mod statistics;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::Path;
 
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::Diagnostic;
 use crate::evm::assembly::instruction::Instruction;
 
 use self::function::block::Block;
+use self::function::registry::Registry;
 use self::function::Function;
 
 ///
@@ -20,33 +25,134 @@ use self::function::Function;
 pub struct EtherealIR {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
-    /// The all-inlined function representation.
+    /// The entry function, where the calls which cannot be recovered are inlined.
     pub function: Function,
+    /// The recovered internal functions.
+    pub functions: Vec<Function>,
+    /// The warnings about the internal functions which have been inlined instead.
+    pub recovery_warnings: Vec<Diagnostic>,
 }
 
 impl EtherealIR {
     /// The default entry function name.
     pub const DEFAULT_ENTRY_FUNCTION_NAME: &'static str = "function_main";
 
+    /// The default maximum number of clones of a single block in a function.
+    pub const CLONE_LIMIT_DEFAULT: usize = 256;
+
+    /// The Graphviz output string initial capacity.
+    pub const DOT_STRING_DEFAULT_CAPACITY: usize = 16384;
+
     /// The blocks hashmap initial capacity.
     pub const BLOCKS_HASHMAP_DEFAULT_CAPACITY: usize = 64;
 
     ///
     /// Assembles a sequence of functions from the sequence of instructions.
     ///
+    /// Every block is cloned for each distinct initial stack state at most `clone_limit` times.
+    ///
     pub fn new(
         solc_version: semver::Version,
         blocks: HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        clone_limit: usize,
     ) -> anyhow::Result<Self> {
-        let mut visited = HashSet::with_capacity(blocks.len());
-        let function = Function::new(solc_version.clone(), &blocks, &mut visited)?;
+        let mut registry = Registry::new(clone_limit);
+        let function =
+            Function::new(solc_version.clone(), &blocks, &mut registry).map_err(|error| {
+                error.downcast::<Diagnostic>().unwrap_or_else(|error| {
+                    Diagnostic::new_error(DiagnosticCode::EthIR, error.to_string())
+                })
+            })?;
 
         Ok(Self {
             solc_version,
             function,
+            functions: registry.functions,
+            recovery_warnings: registry.warnings,
         })
     }
 
+    ///
+    /// Returns the number of blocks cloned for different initial stack states.
+    ///
+    pub fn blocks_cloned(&self) -> usize {
+        std::iter::once(&self.function)
+            .chain(self.functions.iter())
+            .flat_map(|function| function.blocks.values())
+            .map(|blocks| blocks.len().saturating_sub(1))
+            .sum()
+    }
+
+    ///
+    /// Returns the warnings about the internal functions which could not be recovered, and the
+    /// blocks truncated to `INVALID` by the stack simulation.
+    ///
+    /// A block may be truncated in several clones, so each block instruction is reported once.
+    ///
+    pub fn warnings(&self) -> Vec<Diagnostic> {
+        let mut reported = HashSet::new();
+        let truncations = std::iter::once(&self.function)
+            .chain(self.functions.iter())
+            .flat_map(|function| function.truncations.iter())
+            .filter(|truncation| reported.insert((truncation.key.clone(), truncation.index)))
+            .map(Diagnostic::from);
+        self.recovery_warnings
+            .iter()
+            .cloned()
+            .chain(truncations)
+            .collect()
+    }
+
+    ///
+    /// Returns the Graphviz control-flow graph of the `code_type` code.
+    ///
+    /// Each function is a separate cluster of its block clones.
+    ///
+    pub fn to_dot(&self, code_type: compiler_llvm_context::CodeType) -> String {
+        let mut dot = String::with_capacity(Self::DOT_STRING_DEFAULT_CAPACITY);
+        dot.push_str("digraph ethir {\n    node [shape=box];\n");
+        for function in std::iter::once(&self.function).chain(self.functions.iter()) {
+            function
+                .write_dot(code_type, &mut dot)
+                .expect("Writing to a string never fails");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    ///
+    /// Writes the deploy and runtime code control-flow graphs of the contract to `directory`.
+    ///
+    /// The file names are derived from the `contract_path`, e.g. `A.sol_A.runtime.dot`.
+    ///
+    pub fn write_dot(&self, directory: &Path, contract_path: &str) -> anyhow::Result<()> {
+        std::fs::create_dir_all(directory).map_err(|error| {
+            anyhow::anyhow!("Directory {:?} creating error: {}", directory, error)
+        })?;
+
+        let file_name: String = contract_path
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() || "._-".contains(character) {
+                    character
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        for (code_type, suffix) in [
+            (compiler_llvm_context::CodeType::Deploy, "deploy"),
+            (compiler_llvm_context::CodeType::Runtime, "runtime"),
+        ] {
+            let file_path = directory.join(format!("{}.{}.dot", file_name, suffix));
+            std::fs::write(&file_path, self.to_dot(code_type)).map_err(|error| {
+                anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
+            })?;
+        }
+
+        Ok(())
+    }
+
     ///
     /// Gets blocks for the specified type of the contract code.
     ///
@@ -55,19 +161,26 @@ impl EtherealIR {
         code_type: compiler_llvm_context::CodeType,
         instructions: &[Instruction],
     ) -> anyhow::Result<HashMap<compiler_llvm_context::FunctionBlockKey, Block>> {
-        let mut blocks = HashMap::with_capacity(Self::BLOCKS_HASHMAP_DEFAULT_CAPACITY);
+        let mut blocks: HashMap<compiler_llvm_context::FunctionBlockKey, Block> =
+            HashMap::with_capacity(Self::BLOCKS_HASHMAP_DEFAULT_CAPACITY);
         let mut offset = 0;
+        let mut previous_key: Option<compiler_llvm_context::FunctionBlockKey> = None;
 
         while offset < instructions.len() {
             let (block, size) = Block::try_from_instructions(
                 solc_version.clone(),
                 code_type,
                 &instructions[offset..],
-            )?;
-            blocks.insert(
-                compiler_llvm_context::FunctionBlockKey::new(code_type, block.key.tag.clone()),
-                block,
-            );
+            )
+            .map_err(|error| Diagnostic::new_error(DiagnosticCode::EthIR, error.to_string()))?;
+            let key =
+                compiler_llvm_context::FunctionBlockKey::new(code_type, block.key.tag.clone());
+            if let Some(previous_key) = previous_key.replace(key.clone()) {
+                if let Some(previous_block) = blocks.get_mut(&previous_key) {
+                    previous_block.next_key = Some(key.clone());
+                }
+            }
+            blocks.insert(key, block);
             offset += size;
         }
 
@@ -81,6 +194,9 @@ where
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         self.function.declare(context)?;
+        for function in self.functions.iter_mut() {
+            function.declare(context)?;
+        }
 
         Ok(())
     }
@@ -89,6 +205,9 @@ where
         context.evm_mut().stack = vec![];
 
         self.function.into_llvm(context)?;
+        for function in self.functions.into_iter() {
+            function.into_llvm(context)?;
+        }
 
         Ok(())
     }
@@ -97,6 +216,9 @@ where
 impl std::fmt::Display for EtherealIR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.function)?;
+        for function in self.functions.iter() {
+            writeln!(f, "{}", function)?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,28 @@
+//!
+//! The Ethereal IR construction statistics.
+//!
+
+use std::time::Duration;
+
+///
+/// The Ethereal IR construction statistics.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Statistics {
+    /// The construction time.
+    pub time: Duration,
+    /// The number of blocks cloned for different initial stack states.
+    pub blocks_cloned: usize,
+}
+
+impl Statistics {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(time: Duration, blocks_cloned: usize) -> Self {
+        Self {
+            time,
+            blocks_cloned,
+        }
+    }
+}
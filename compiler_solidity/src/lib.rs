@@ -3,22 +3,38 @@
 //!
 
 pub(crate) mod build;
+pub(crate) mod diagnostic;
 pub(crate) mod dump_flag;
 pub(crate) mod evm;
+pub(crate) mod linker;
 pub(crate) mod project;
 pub(crate) mod solc;
+pub(crate) mod verification;
 pub(crate) mod yul;
 
+pub use self::build::artifact::Format as ArtifactFormat;
 pub use self::build::contract::Contract as ContractBuild;
+pub use self::build::manifest::Manifest;
 pub use self::build::Build;
+pub use self::diagnostic::code::Code as DiagnosticCode;
+pub use self::diagnostic::list::List as DiagnosticList;
+pub use self::diagnostic::severity::Severity as DiagnosticSeverity;
+pub use self::diagnostic::Diagnostic;
 pub use self::dump_flag::DumpFlag;
+pub use self::linker::hash as bytecode_hash;
+pub use self::linker::link;
+pub use self::linker::reference::Reference as LinkReference;
+pub use self::linker::References as LinkReferences;
 pub use self::project::contract::state::State as ContractState;
+pub use self::project::contract::statistics::Statistics as ContractStatistics;
 pub use self::project::contract::Contract as ProjectContract;
+pub use self::project::metadata::Metadata as ProjectMetadata;
 pub use self::project::Project;
 pub use self::solc::combined_json::contract::Contract as SolcCombinedJsonContract;
 pub use self::solc::combined_json::CombinedJson as SolcCombinedJson;
 pub use self::solc::pipeline::Pipeline as SolcPipeline;
 pub use self::solc::standard_json::input::language::Language as SolcStandardJsonInputLanguage;
+pub use self::solc::standard_json::input::settings::requested::Requested as SolcStandardJsonInputSettingsRequested;
 pub use self::solc::standard_json::input::settings::selection::Selection as SolcStandardJsonInputSettingsSelection;
 pub use self::solc::standard_json::input::settings::Settings as SolcStandardJsonInputSettings;
 pub use self::solc::standard_json::input::source::Source as SolcStandardJsonInputSource;
@@ -26,5 +42,11 @@ pub use self::solc::standard_json::input::Input as SolcStandardJsonInput;
 pub use self::solc::standard_json::output::contract::evm::bytecode::Bytecode as SolcStandardJsonOutputContractEVMBytecode;
 pub use self::solc::standard_json::output::contract::evm::EVM as SolcStandardJsonOutputContractEVM;
 pub use self::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
+pub use self::solc::standard_json::output::error::source_location::SourceLocation as SolcStandardJsonOutputErrorSourceLocation;
+pub use self::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 pub use self::solc::standard_json::output::Output as SolcStandardJsonOutput;
 pub use self::solc::Compiler as SolcCompiler;
+pub use self::verification::contract::Contract as VerificationContract;
+pub use self::verification::report::difference::Difference as VerificationDifference;
+pub use self::verification::report::Report as VerificationReport;
+pub use self::verification::Artifact as VerificationArtifact;
@@ -0,0 +1,270 @@
+//!
+//! The library linker.
+//!
+
+pub mod reference;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::Diagnostic;
+
+use self::reference::Reference;
+
+///
+/// The library link references, where the keys are the source file names and contract names.
+///
+pub type References = BTreeMap<String, BTreeMap<String, Vec<Reference>>>;
+
+/// The library address and placeholder size in bytes.
+pub const ADDRESS_SIZE: usize = 20;
+
+/// The bytecode word size in bytes.
+pub const WORD_SIZE: usize = compiler_common::SIZE_FIELD;
+
+///
+/// Returns the hexadecimal placeholder of the unlinked library.
+///
+/// The placeholder is the beginning of the library path `keccak256` hash, so it can be found in
+/// the bytecode and replaced with the library address later.
+///
+pub fn placeholder(path: &str) -> String {
+    compiler_llvm_context::keccak256(path.as_bytes())[..ADDRESS_SIZE * 2].to_owned()
+}
+
+///
+/// Finds the placeholders of the unlinked `libraries` in the bytecode.
+///
+pub fn references(bytecode: &[u8], libraries: &BTreeSet<String>) -> References {
+    let mut references = References::new();
+    for path in libraries.iter() {
+        let placeholder = hex::decode(placeholder(path.as_str())).expect("Always valid");
+        let offsets: Vec<Reference> = find(bytecode, placeholder.as_slice())
+            .into_iter()
+            .map(|offset| Reference::new(offset, ADDRESS_SIZE))
+            .collect();
+        if offsets.is_empty() {
+            continue;
+        }
+
+        let (file, name) = path.rsplit_once(':').unwrap_or(("", path.as_str()));
+        references
+            .entry(file.to_owned())
+            .or_insert_with(BTreeMap::new)
+            .insert(name.to_owned(), offsets);
+    }
+    references
+}
+
+///
+/// Returns the warning about the unlinked libraries, if there are any `references`.
+///
+/// The placeholders are valid addresses, so the unlinked bytecode can be deployed, but calling
+/// the libraries will silently call the nonexistent contracts.
+///
+pub fn unlinked_warning(references: &References) -> Option<Diagnostic> {
+    let libraries: Vec<String> = references
+        .iter()
+        .flat_map(|(file, contracts)| {
+            contracts
+                .keys()
+                .map(move |name| format!("`{}:{}`", file, name))
+        })
+        .collect();
+    if libraries.is_empty() {
+        return None;
+    }
+
+    Some(Diagnostic::new_warning(
+        DiagnosticCode::UnlinkedLibraries,
+        format!(
+            "The bytecode contains the placeholders of the unlinked libraries {}. It must not be deployed before being linked with `--link`, since the placeholders would be called as the library addresses",
+            libraries.join(", ")
+        ),
+    ))
+}
+
+///
+/// Replaces the placeholders of the `libraries` in the bytecode with their addresses.
+///
+/// Returns the paths of the libraries which have been found in the bytecode.
+///
+pub fn link(
+    bytecode: &mut [u8],
+    libraries: &BTreeMap<String, BTreeMap<String, String>>,
+) -> anyhow::Result<BTreeSet<String>> {
+    let mut linked = BTreeSet::new();
+    for (file, contracts) in libraries.iter() {
+        for (name, address) in contracts.iter() {
+            let path = format!("{}:{}", file, name);
+            let address = parse_address(path.as_str(), address.as_str())?;
+            let placeholder = hex::decode(placeholder(path.as_str())).expect("Always valid");
+
+            let offsets = find(bytecode, placeholder.as_slice());
+            for offset in offsets.iter() {
+                bytecode[*offset..*offset + ADDRESS_SIZE].copy_from_slice(address.as_slice());
+            }
+            if !offsets.is_empty() {
+                linked.insert(path);
+            }
+        }
+    }
+    Ok(linked)
+}
+
+///
+/// Computes the hexadecimal zkEVM hash of the `bytecode`.
+///
+/// Uses the same routine as the code generator, so the hash of the linked bytecode can be
+/// compared with the one of a contract built with the libraries specified.
+///
+pub fn hash(bytecode: &[u8]) -> anyhow::Result<String> {
+    if bytecode.len() % WORD_SIZE != 0 {
+        anyhow::bail!(
+            "The bytecode size {} is not a multiple of the word size {}",
+            bytecode.len(),
+            WORD_SIZE
+        );
+    }
+    let words: Vec<[u8; WORD_SIZE]> = bytecode
+        .chunks(WORD_SIZE)
+        .map(|chunk| chunk.try_into().expect("Always valid"))
+        .collect();
+    zkevm_assembly::zkevm_opcode_defs::utils::bytecode_to_code_hash(words.as_slice())
+        .map(hex::encode)
+        .map_err(|_| anyhow::anyhow!("The bytecode of {} words cannot be hashed", words.len()))
+}
+
+///
+/// Parses the `0x`-prefixed hexadecimal address of the library at `path`.
+///
+pub fn parse_address(path: &str, address: &str) -> anyhow::Result<Vec<u8>> {
+    let hexadecimal = address.strip_prefix("0x").ok_or_else(|| {
+        anyhow::anyhow!(
+            "The library `{}` address `{}` must be prefixed with `0x`",
+            path,
+            address
+        )
+    })?;
+    if hexadecimal.len() != ADDRESS_SIZE * 2 {
+        anyhow::bail!(
+            "The library `{}` address `{}` must be {} hexadecimal digits long, found {}",
+            path,
+            address,
+            ADDRESS_SIZE * 2,
+            hexadecimal.len()
+        );
+    }
+    hex::decode(hexadecimal).map_err(|error| {
+        anyhow::anyhow!(
+            "The library `{}` address `{}` is not hexadecimal: {}",
+            path,
+            address,
+            error
+        )
+    })
+}
+
+///
+/// Returns the offsets of all occurrences of `pattern` in `data`.
+///
+fn find(data: &[u8], pattern: &[u8]) -> Vec<usize> {
+    data.windows(pattern.len())
+        .enumerate()
+        .filter(|(_, window)| *window == pattern)
+        .map(|(offset, _)| offset)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::diagnostic::code::Code as DiagnosticCode;
+    use crate::project::Project;
+
+    const LIBRARY_PATH: &str = "Library.sol:Library";
+
+    const YUL: &str = r#"
+object "Test" {
+    code {
+        {
+            let size := datasize("Test_deployed")
+            codecopy(0, dataoffset("Test_deployed"), size)
+            return(0, size)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let library := linkersymbol("Library.sol:Library")
+                let success := delegatecall(gas(), library, 0, calldatasize(), 0, 0)
+                if iszero(success) { revert(0, 0) }
+                return(0, 0)
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn library_call_offsets() {
+        let project = Project::try_from_test_yul(YUL, &semver::Version::new(0, 8, 15))
+            .expect("Parsing error");
+        let mut build = project
+            .compile_all(compiler_llvm_context::OptimizerSettings::cycles(), vec![])
+            .expect("Compilation error");
+        let contract = build.contracts.remove("Test").expect("Always exists");
+        assert!(build.warnings.iter().any(|warning| {
+            warning.code == DiagnosticCode::UnlinkedLibraries
+                && warning.message.contains(LIBRARY_PATH)
+        }));
+        assert_eq!(
+            super::hash(contract.build.bytecode.as_slice()).expect("Hashing error"),
+            contract.build.hash
+        );
+
+        let (file, name) = LIBRARY_PATH.rsplit_once(':').expect("Always valid");
+        let references = &contract.link_references[file][name];
+        assert!(!references.is_empty());
+        let placeholder = hex::decode(super::placeholder(LIBRARY_PATH)).expect("Always valid");
+        for reference in references.iter() {
+            assert_eq!(reference.length, super::ADDRESS_SIZE);
+            assert_eq!(
+                reference.start % super::WORD_SIZE,
+                super::WORD_SIZE - super::ADDRESS_SIZE
+            );
+            assert_eq!(
+                &contract.build.bytecode[reference.start..reference.start + reference.length],
+                placeholder.as_slice()
+            );
+        }
+
+        let address = "0x1234567890123456789012345678901234567890";
+        let mut libraries = BTreeMap::new();
+        libraries
+            .entry(file.to_owned())
+            .or_insert_with(BTreeMap::new)
+            .insert(name.to_owned(), address.to_owned());
+        let mut bytecode = contract.build.bytecode.clone();
+        let linked = super::link(bytecode.as_mut_slice(), &libraries).expect("Linking error");
+        assert!(linked.contains(LIBRARY_PATH));
+
+        let address = super::parse_address(LIBRARY_PATH, address).expect("Always valid");
+        for (offset, (original, linked)) in contract
+            .build
+            .bytecode
+            .iter()
+            .zip(bytecode.iter())
+            .enumerate()
+        {
+            match references.iter().find(|reference| {
+                (reference.start..reference.start + reference.length).contains(&offset)
+            }) {
+                Some(reference) => assert_eq!(*linked, address[offset - reference.start]),
+                None => assert_eq!(original, linked),
+            }
+        }
+    }
+}
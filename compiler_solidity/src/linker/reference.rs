@@ -0,0 +1,28 @@
+//!
+//! The library link reference.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The library link reference.
+///
+/// Has the same shape as the `solc` `linkReferences` entry.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// The placeholder offset in the bytecode, in bytes.
+    pub start: usize,
+    /// The placeholder length, in bytes.
+    pub length: usize,
+}
+
+impl Reference {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: usize, length: usize) -> Self {
+        Self { start, length }
+    }
+}
@@ -4,18 +4,24 @@
 
 pub mod source;
 pub mod state;
+pub mod statistics;
 
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Instant;
 
 use compiler_llvm_context::WriteLLVM;
 
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::list::List as DiagnosticList;
+use crate::diagnostic::Diagnostic;
 use crate::dump_flag::DumpFlag;
 use crate::project::Project;
 
 use self::source::Source;
 use self::state::State;
+use self::statistics::Statistics;
 
 ///
 /// The contract data representation.
@@ -28,6 +34,8 @@ pub struct Contract {
     pub source: Source,
     /// The ABI specification JSON.
     pub abi: Option<serde_json::Value>,
+    /// The compilation statistics.
+    pub statistics: Statistics,
 }
 
 impl Contract {
@@ -35,7 +43,12 @@ impl Contract {
     /// A shortcut constructor.
     ///
     pub fn new(path: String, source: Source, abi: Option<serde_json::Value>) -> Self {
-        Self { path, source, abi }
+        Self {
+            path,
+            source,
+            abi,
+            statistics: Statistics::default(),
+        }
     }
 
     ///
@@ -62,14 +75,14 @@ impl Contract {
     }
 
     ///
-    /// Compiles the specified contract, setting its build artifacts.
+    /// Compiles the specified contract, returning its build artifacts, statistics and warnings.
     ///
     pub fn compile(
         mut self,
         project: Arc<RwLock<Project>>,
         optimizer_settings: compiler_llvm_context::OptimizerSettings,
         dump_flags: Vec<DumpFlag>,
-    ) -> anyhow::Result<compiler_llvm_context::Build> {
+    ) -> anyhow::Result<(compiler_llvm_context::Build, Statistics, Vec<Diagnostic>)> {
         let llvm = inkwell::context::Context::create();
         let optimizer = compiler_llvm_context::Optimizer::new(optimizer_settings)?;
         let dump_flags = compiler_llvm_context::DumpFlag::initialize(
@@ -101,24 +114,69 @@ impl Contract {
             }
         };
 
+        if let Source::EVM(ref mut evm) = self.source {
+            let project = project.read().expect("Sync");
+            evm.assembly.ethereal_ir_clone_limit = project.ethereal_ir_clone_limit;
+            evm.assembly.ethereal_ir_dot_directory = project.ethereal_ir_dot_directory.clone();
+        }
         let factory_dependencies = self.drain_factory_dependencies();
+        let (ethereal_ir_statistics, ethereal_ir_warnings) = match self.source {
+            Source::EVM(ref evm) => (
+                Some(evm.assembly.ethereal_ir_statistics.clone()),
+                Some(evm.assembly.ethereal_ir_warnings.clone()),
+            ),
+            Source::Yul(_) => (None, None),
+        };
+        let mut statistics = self.statistics;
 
+        let llvm_ir_start = Instant::now();
         self.source.declare(&mut context).map_err(|error| {
-            anyhow::anyhow!(
-                "The contract `{}` LLVM IR generator declaration pass error: {}",
-                self.path,
-                error
+            DiagnosticList::from_error(
+                error,
+                DiagnosticCode::CodeGeneration,
+                Some(self.path.as_str()),
             )
         })?;
         self.source.into_llvm(&mut context).map_err(|error| {
-            anyhow::anyhow!(
-                "The contract `{}` LLVM IR generator definition pass error: {}",
-                self.path,
-                error
+            DiagnosticList::from_error(
+                error,
+                DiagnosticCode::CodeGeneration,
+                Some(self.path.as_str()),
             )
         })?;
 
+        let mut llvm_ir_time = llvm_ir_start.elapsed();
+        if let Some(ethereal_ir_statistics) = ethereal_ir_statistics {
+            let ethereal_ir_statistics = *ethereal_ir_statistics.lock().expect("Sync");
+            llvm_ir_time = llvm_ir_time.saturating_sub(ethereal_ir_statistics.time);
+            statistics.ethereal_ir_time_ms =
+                Some(Statistics::milliseconds(ethereal_ir_statistics.time));
+            statistics.blocks_cloned = Some(ethereal_ir_statistics.blocks_cloned);
+        }
+        statistics.llvm_ir_time_ms = Statistics::milliseconds(llvm_ir_time);
+        let warnings = match ethereal_ir_warnings {
+            Some(ethereal_ir_warnings) => {
+                std::mem::take(&mut *ethereal_ir_warnings.lock().expect("Sync"))
+                    .into_iter()
+                    .map(|warning| warning.with_contract_path(self.path.as_str()))
+                    .collect()
+            }
+            None => vec![],
+        };
+
+        let llvm_build_start = Instant::now();
         let mut build = context.build(self.path.as_str())?;
+        statistics.llvm_build_time_ms = Statistics::milliseconds(llvm_build_start.elapsed());
+        let metadata = {
+            let project = project.read().expect("Sync");
+            match project.metadata.as_ref() {
+                Some(metadata) if project.embed_metadata_hash => Some(metadata.to_owned()),
+                _ => None,
+            }
+        };
+        if let Some(metadata) = metadata {
+            metadata.embed_hash(&mut build)?;
+        }
         for dependency in factory_dependencies.into_iter() {
             let full_path = project
                 .read()
@@ -137,14 +195,22 @@ impl Contract {
                 Some(_) => {
                     panic!("Dependency `{}` must be built at this point", full_path)
                 }
-                None => anyhow::bail!(
-                    "Dependency contract `{}` not found in the project",
-                    full_path
-                ),
+                None => {
+                    return Err(Diagnostic::new_error(
+                        DiagnosticCode::Dependency,
+                        format!(
+                            "Dependency contract `{}` not found in the project",
+                            full_path
+                        ),
+                    )
+                    .with_contract_path(self.path.as_str())
+                    .into())
+                }
             };
             build.factory_dependencies.insert(hash, full_path);
         }
-        Ok(build)
+        statistics.bytecode_size = build.bytecode.len();
+        Ok((build, statistics, warnings))
     }
 }
 
@@ -0,0 +1,42 @@
+//!
+//! The contract compilation statistics.
+//!
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+///
+/// The contract compilation statistics.
+///
+/// The times are in milliseconds. The dependencies which are compiled on demand during the
+/// LLVM IR generation are included in the dependent contract LLVM IR generation time.
+///
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    /// The Yul parsing and analysis time. The EVM legacy assembly is parsed with the `solc` output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_time_ms: Option<f64>,
+    /// The Ethereal IR construction time. Only for the EVM legacy assembly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ethereal_ir_time_ms: Option<f64>,
+    /// The LLVM IR generation time, excluding the Ethereal IR construction.
+    pub llvm_ir_time_ms: f64,
+    /// The LLVM optimization and assembly time, which are performed together by the LLVM context.
+    pub llvm_build_time_ms: f64,
+    /// The bytecode size in bytes.
+    pub bytecode_size: usize,
+    /// The number of blocks cloned for different initial stack states. Only for the EVM legacy assembly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks_cloned: Option<usize>,
+}
+
+impl Statistics {
+    ///
+    /// Converts the duration to milliseconds.
+    ///
+    pub fn milliseconds(duration: Duration) -> f64 {
+        duration.as_secs_f64() * 1000.0
+    }
+}
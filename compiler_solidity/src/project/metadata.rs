@@ -0,0 +1,191 @@
+//!
+//! The `zksolc` project metadata.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::linker::WORD_SIZE;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::Input as SolcStandardJsonInput;
+
+///
+/// The `zksolc` project metadata.
+///
+/// Contains everything needed to reproduce the build. Its hash can be embedded into the bytecode,
+/// so the deployed contracts can be matched against the sources.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The `solc` version.
+    pub solc_version: String,
+    /// The `zksolc` version.
+    pub zksolc_version: String,
+    /// The `solc` pipeline.
+    pub pipeline: SolcPipeline,
+    /// Whether the LLVM optimizer is enabled.
+    pub optimize: bool,
+    /// The `solc` optimizer settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc_optimizer: Option<serde_json::Value>,
+    /// The source file `keccak256` hashes.
+    pub source_hashes: BTreeMap<String, String>,
+}
+
+impl Metadata {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solc_version: &semver::Version,
+        pipeline: SolcPipeline,
+        optimize: bool,
+        solc_optimizer: Option<serde_json::Value>,
+        source_hashes: BTreeMap<String, String>,
+    ) -> Self {
+        Self {
+            solc_version: solc_version.to_string(),
+            zksolc_version: env!("CARGO_PKG_VERSION").to_owned(),
+            pipeline,
+            optimize,
+            solc_optimizer,
+            source_hashes,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which takes the `solc` settings and sources from the input.
+    ///
+    pub fn new_from_input(
+        solc_version: &semver::Version,
+        pipeline: SolcPipeline,
+        optimize: bool,
+        input: &SolcStandardJsonInput,
+    ) -> Self {
+        Self::new(
+            solc_version,
+            pipeline,
+            optimize,
+            Some(serde_json::to_value(&input.settings.optimizer).expect("Always valid")),
+            input
+                .sources
+                .iter()
+                .map(|(path, source)| (path.to_owned(), Self::hash_source(source.content.as_str())))
+                .collect(),
+        )
+    }
+
+    ///
+    /// Returns the source file `keccak256` hash.
+    ///
+    pub fn hash_source(content: &str) -> String {
+        compiler_llvm_context::keccak256(content.as_bytes())
+    }
+
+    ///
+    /// Returns the `keccak256` hash of the metadata JSON.
+    ///
+    pub fn keccak256(&self) -> String {
+        let json = serde_json::to_vec(self).expect("Always valid");
+        compiler_llvm_context::keccak256(json.as_slice())
+    }
+
+    ///
+    /// Embeds the metadata hash into the `build` bytecode, and recomputes the bytecode hash.
+    ///
+    /// The hash is written to the last bytecode word. Since the zkEVM bytecode must consist of an
+    /// odd number of words, a zero word is inserted before it if needed.
+    ///
+    pub fn embed_hash(&self, build: &mut compiler_llvm_context::Build) -> anyhow::Result<()> {
+        if build.bytecode.len() % WORD_SIZE != 0 {
+            anyhow::bail!(
+                "The bytecode size {} is not a multiple of the word size {}",
+                build.bytecode.len(),
+                WORD_SIZE
+            );
+        }
+
+        if (build.bytecode.len() / WORD_SIZE) % 2 != 0 {
+            build.bytecode.extend([0; WORD_SIZE]);
+        }
+        build.bytecode.extend(hex::decode(self.keccak256())?);
+        build.hash = crate::linker::hash(build.bytecode.as_slice())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::linker::WORD_SIZE;
+    use crate::project::Project;
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+
+    use super::Metadata;
+
+    const YUL: &str = r#"
+object "Test" {
+    code {
+        {
+            let size := datasize("Test_deployed")
+            codecopy(0, dataoffset("Test_deployed"), size)
+            return(0, size)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, 42)
+                return(0, 32)
+            }
+        }
+    }
+}
+"#;
+
+    fn build(metadata: Metadata, embed_hash: bool) -> compiler_llvm_context::Build {
+        let version = semver::Version::new(0, 8, 15);
+        let mut project = Project::try_from_test_yul(YUL, &version).expect("Parsing error");
+        project.set_metadata(metadata, embed_hash);
+        project
+            .compile_all(compiler_llvm_context::OptimizerSettings::cycles(), vec![])
+            .expect("Compilation error")
+            .contracts
+            .remove("Test")
+            .expect("Always exists")
+            .build
+    }
+
+    #[test]
+    fn embed_hash() {
+        let metadata = Metadata::new(
+            &semver::Version::new(0, 8, 15),
+            SolcPipeline::Yul,
+            true,
+            None,
+            BTreeMap::from([("Test".to_owned(), Metadata::hash_source(YUL))]),
+        );
+        let plain = build(metadata.clone(), false);
+        let embedded = build(metadata.clone(), true);
+
+        assert_eq!(embedded.bytecode.len() % WORD_SIZE, 0);
+        assert_eq!((embedded.bytecode.len() / WORD_SIZE) % 2, 1);
+        assert_eq!(
+            &embedded.bytecode[..plain.bytecode.len()],
+            plain.bytecode.as_slice()
+        );
+        assert_eq!(
+            hex::encode(&embedded.bytecode[embedded.bytecode.len() - WORD_SIZE..]),
+            metadata.keccak256()
+        );
+        assert_eq!(
+            crate::linker::hash(embedded.bytecode.as_slice()).expect("Hashing error"),
+            embedded.hash
+        );
+        assert_ne!(embedded.hash, plain.hash);
+    }
+}
@@ -3,25 +3,36 @@
 //!
 
 pub mod contract;
+pub mod metadata;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::RwLock;
+use std::time::Instant;
 
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
 use crate::build::contract::Contract as ContractBuild;
 use crate::build::Build;
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::list::List as DiagnosticList;
+use crate::diagnostic::Diagnostic;
 use crate::dump_flag::DumpFlag;
 use crate::project::contract::source::Source;
 use crate::project::contract::state::State;
+use crate::yul::analyzer::Analyzer;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::state::State as ContractState;
+use self::contract::statistics::Statistics as ContractStatistics;
 use self::contract::Contract;
+use self::metadata::Metadata;
 
 ///
 /// The processes input data representation.
@@ -36,6 +47,18 @@ pub struct Project {
     pub identifier_paths: BTreeMap<String, String>,
     /// The library addresses.
     pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// The libraries without addresses, which are replaced with placeholders in the bytecode.
+    pub unlinked_libraries: Mutex<BTreeSet<String>>,
+    /// The project metadata.
+    pub metadata: Option<Metadata>,
+    /// Whether to embed the metadata hash into the bytecode.
+    pub embed_metadata_hash: bool,
+    /// The number of compilation threads. Uses the global thread pool if unset.
+    pub threads: Option<usize>,
+    /// The Ethereal IR block clone limit. Uses the default one if unset.
+    pub ethereal_ir_clone_limit: Option<usize>,
+    /// The directory to write the Ethereal IR control-flow graphs to.
+    pub ethereal_ir_dot_directory: Option<PathBuf>,
 }
 
 impl Project {
@@ -60,9 +83,70 @@ impl Project {
                 .collect(),
             identifier_paths,
             libraries,
+            unlinked_libraries: Mutex::new(BTreeSet::new()),
+            metadata: None,
+            embed_metadata_hash: false,
+            threads: None,
+            ethereal_ir_clone_limit: None,
+            ethereal_ir_dot_directory: None,
         }
     }
 
+    ///
+    /// Sets the project metadata.
+    ///
+    /// If `embed_hash` is set, the metadata hash is embedded into the bytecode of every contract.
+    ///
+    pub fn set_metadata(&mut self, metadata: Metadata, embed_hash: bool) {
+        self.metadata = Some(metadata);
+        self.embed_metadata_hash = embed_hash;
+    }
+
+    ///
+    /// Sets the number of compilation threads.
+    ///
+    /// With a single thread, the contracts are compiled sequentially in the current thread.
+    ///
+    pub fn set_threads(&mut self, threads: Option<usize>) {
+        self.threads = threads;
+    }
+
+    ///
+    /// Sets the maximum number of clones of a single block in the Ethereal IR functions.
+    ///
+    /// Only affects the EVM legacy assembly pipeline.
+    ///
+    pub fn set_ethereal_ir_clone_limit(&mut self, clone_limit: Option<usize>) {
+        self.ethereal_ir_clone_limit = clone_limit;
+    }
+
+    ///
+    /// Sets the directory to write the Ethereal IR control-flow graphs to.
+    ///
+    /// Only affects the EVM legacy assembly pipeline.
+    ///
+    pub fn set_ethereal_ir_dot_directory(&mut self, directory: Option<PathBuf>) {
+        self.ethereal_ir_dot_directory = directory;
+    }
+
+    ///
+    /// Returns the source code hashes of the Yul contracts, keyed by their paths.
+    ///
+    /// Used in the Yul mode, where the contract sources are the input files.
+    ///
+    pub fn yul_source_hashes(&self) -> BTreeMap<String, String> {
+        self.contract_states
+            .iter()
+            .filter_map(|(path, state)| match state {
+                ContractState::Source(Contract {
+                    source: Source::Yul(yul),
+                    ..
+                }) => Some((path.to_owned(), Metadata::hash_source(yul.source.as_str()))),
+                _ => None,
+            })
+            .collect()
+    }
+
     ///
     /// Compiles the specified contract, setting its build artifacts.
     ///
@@ -88,10 +172,32 @@ impl Project {
 
                 let identifier = contract.identifier().to_owned();
                 let abi = contract.abi.take();
+                let metadata = project.read().expect("Sync").metadata.to_owned();
                 match contract.compile(project.clone(), optimizer_settings, dump_flags) {
-                    Ok(build) => {
-                        let build =
-                            ContractBuild::new(contract_path.to_owned(), identifier, build, abi);
+                    Ok((build, statistics, mut warnings)) => {
+                        let link_references = crate::linker::references(
+                            build.bytecode.as_slice(),
+                            &project
+                                .read()
+                                .expect("Sync")
+                                .unlinked_libraries
+                                .lock()
+                                .expect("Sync"),
+                        );
+                        warnings.extend(
+                            crate::linker::unlinked_warning(&link_references)
+                                .map(|warning| warning.with_contract_path(contract_path)),
+                        );
+                        let build = ContractBuild::new(
+                            contract_path.to_owned(),
+                            identifier,
+                            build,
+                            abi,
+                            metadata,
+                            link_references,
+                            statistics,
+                            warnings,
+                        );
                         project
                             .write()
                             .expect("Sync")
@@ -100,11 +206,16 @@ impl Project {
                         waiter.1.notify_all();
                     }
                     Err(error) => {
+                        let error = DiagnosticList::from_error(
+                            error,
+                            DiagnosticCode::CodeGeneration,
+                            Some(contract_path),
+                        );
                         project
                             .write()
                             .expect("Sync")
                             .contract_states
-                            .insert(contract_path.to_owned(), ContractState::Error(error));
+                            .insert(contract_path.to_owned(), ContractState::Error(error.into()));
                         waiter.1.notify_all();
                     }
                 }
@@ -134,12 +245,29 @@ impl Project {
     ///
     /// Compiles all contracts, returning their build artifacts.
     ///
+    /// The failed contracts do not stop the compilation. Their errors are collected
+    /// into the build, so all of them can be reported at once.
+    ///
+    /// The builds and diagnostics are collected by the contract paths, so they are reported in
+    /// the same order with any number of threads. However, the output printed during the
+    /// compilation, such as the LLVM crash messages, is only deterministic with a single thread.
+    /// With several threads, it follows the completion order.
+    ///
+    /// If any dump flag is set, the contracts are compiled sequentially in the order of their
+    /// paths, so the dumps of different contracts are not interleaved. It overrides the number
+    /// of threads set with `set_threads`.
+    ///
     #[allow(clippy::needless_collect)]
     pub fn compile_all(
         self,
         optimizer_settings: compiler_llvm_context::OptimizerSettings,
         dump_flags: Vec<DumpFlag>,
     ) -> anyhow::Result<Build> {
+        let threads = if dump_flags.is_empty() {
+            self.threads
+        } else {
+            Some(1)
+        };
         let project = Arc::new(RwLock::new(self));
 
         let contract_paths: Vec<String> = project
@@ -149,29 +277,50 @@ impl Project {
             .keys()
             .cloned()
             .collect();
-        let _: Vec<()> = contract_paths
-            .into_par_iter()
-            .map(|contract_path| {
-                Self::compile(
-                    project.clone(),
-                    contract_path.as_str(),
-                    optimizer_settings.clone(),
-                    dump_flags.clone(),
-                );
-            })
-            .collect();
+        let compile = |contract_path: String| {
+            Self::compile(
+                project.clone(),
+                contract_path.as_str(),
+                optimizer_settings.clone(),
+                dump_flags.clone(),
+            );
+        };
+        match threads {
+            Some(1) => contract_paths.into_iter().for_each(compile),
+            Some(threads) => rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|error| anyhow::anyhow!("Thread pool building error: {}", error))?
+                .install(|| contract_paths.into_par_iter().for_each(compile)),
+            None => contract_paths.into_par_iter().for_each(compile),
+        }
 
         let project = Arc::try_unwrap(project)
             .expect("No other references must exist at this point")
             .into_inner()
             .expect("Sync");
-        let mut build = Build::default();
+        let mut build = Build {
+            metadata: project.metadata,
+            ..Build::default()
+        };
         for (path, state) in project.contract_states.into_iter() {
             match state {
-                State::Build(contract_build) => {
+                State::Build(mut contract_build) => {
+                    build
+                        .warnings
+                        .extend(std::mem::take(&mut contract_build.warnings));
                     build.contracts.insert(path, contract_build);
                 }
-                State::Error(error) => return Err(error),
+                State::Error(error) => {
+                    build.errors.extend(
+                        DiagnosticList::from_error(
+                            error,
+                            DiagnosticCode::CodeGeneration,
+                            Some(path.as_str()),
+                        )
+                        .diagnostics,
+                    );
+                }
                 _ => panic!("Contract `{}` must be built at this point", path),
             }
         }
@@ -184,16 +333,23 @@ impl Project {
     pub fn try_from_default_yul(path: &Path, version: &semver::Version) -> anyhow::Result<Self> {
         let yul = std::fs::read_to_string(path)
             .map_err(|error| anyhow::anyhow!("Yul file {:?} reading error: {}", path, error))?;
+        let parse_start = Instant::now();
         let mut lexer = Lexer::new(yul.clone());
         let path = path.to_string_lossy().to_string();
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error,))?;
+        let object = Object::parse(&mut lexer, None).map_err(|error| {
+            Diagnostic::new_error(DiagnosticCode::YulParsing, error.to_string())
+                .with_contract_path(path.as_str())
+        })?;
+        Analyzer::analyze(&object).map_err(|error| {
+            DiagnosticList::from_error(error, DiagnosticCode::YulAnalysis, Some(path.as_str()))
+        })?;
+
+        let mut contract = Contract::new(path.clone(), Source::new_yul(yul, object), None);
+        contract.statistics.parse_time_ms =
+            Some(ContractStatistics::milliseconds(parse_start.elapsed()));
 
         let mut project_contracts = BTreeMap::new();
-        project_contracts.insert(
-            path.clone(),
-            Contract::new(path, Source::new_yul(yul, object), None),
-        );
+        project_contracts.insert(path, contract);
         Ok(Self::new(
             version.to_owned(),
             project_contracts,
@@ -209,8 +365,13 @@ impl Project {
     pub fn try_from_test_yul(yul: &str, version: &semver::Version) -> anyhow::Result<Self> {
         let mut lexer = Lexer::new(yul.to_owned());
         let path = "Test".to_owned();
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error,))?;
+        let object = Object::parse(&mut lexer, None).map_err(|error| {
+            Diagnostic::new_error(DiagnosticCode::YulParsing, error.to_string())
+                .with_contract_path(path.as_str())
+        })?;
+        Analyzer::analyze(&object).map_err(|error| {
+            DiagnosticList::from_error(error, DiagnosticCode::YulAnalysis, Some(path.as_str()))
+        })?;
 
         let mut project_contracts = BTreeMap::new();
         project_contracts.insert(
@@ -248,11 +409,14 @@ impl compiler_llvm_context::Dependency for Project {
             .get(contract_path.as_str())
         {
             Some(ContractState::Build(build)) => Ok(build.build.hash.to_owned()),
-            Some(ContractState::Error(error)) => anyhow::bail!(
-                "Dependency contract `{}` compiling error: {}",
-                identifier,
-                error
-            ),
+            Some(ContractState::Error(error)) => Err(Diagnostic::new_error(
+                DiagnosticCode::Dependency,
+                format!(
+                    "Dependency contract `{}` compiling error: {}",
+                    identifier, error
+                ),
+            )
+            .into()),
             Some(_) => panic!(
                 "Dependency contract `{}` must be built at this point",
                 contract_path
@@ -286,6 +450,10 @@ impl compiler_llvm_context::Dependency for Project {
             }
         }
 
-        anyhow::bail!("Library `{}` not found in the project", path);
+        self.unlinked_libraries
+            .lock()
+            .expect("Sync")
+            .insert(path.to_owned());
+        Ok(crate::linker::placeholder(path))
     }
 }
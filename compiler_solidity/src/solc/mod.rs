@@ -55,19 +55,7 @@ impl Compiler {
         command.stdin(std::process::Stdio::piped());
         command.stdout(std::process::Stdio::piped());
         command.arg("--standard-json");
-
-        if let Some(base_path) = base_path {
-            command.arg("--base-path");
-            command.arg(base_path);
-        }
-        for include_path in include_paths.into_iter() {
-            command.arg("--include-path");
-            command.arg(include_path);
-        }
-        if let Some(allow_paths) = allow_paths {
-            command.arg("--allow-paths");
-            command.arg(allow_paths);
-        }
+        Self::push_path_arguments(&mut command, &[], base_path, include_paths, allow_paths);
 
         let input_json = serde_json::to_vec(&input).expect("Always valid");
 
@@ -117,9 +105,20 @@ impl Compiler {
         &self,
         paths: &[PathBuf],
         combined_json_argument: &str,
+        remappings: &[String],
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
     ) -> anyhow::Result<CombinedJson> {
         let mut command = std::process::Command::new(self.executable.as_str());
         command.args(paths);
+        Self::push_path_arguments(
+            &mut command,
+            remappings,
+            base_path,
+            include_paths,
+            allow_paths,
+        );
         command.arg("--combined-json");
         command.arg(combined_json_argument);
         let output = command.output().map_err(|error| {
@@ -157,9 +156,20 @@ impl Compiler {
         paths: &[PathBuf],
         output_abi: bool,
         output_hashes: bool,
+        remappings: &[String],
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
     ) -> anyhow::Result<String> {
         let mut command = std::process::Command::new(self.executable.as_str());
         command.args(paths);
+        Self::push_path_arguments(
+            &mut command,
+            remappings,
+            base_path,
+            include_paths,
+            allow_paths,
+        );
         if output_abi {
             command.arg("--abi");
         }
@@ -180,6 +190,31 @@ impl Compiler {
         Ok(String::from_utf8_lossy(output.stdout.as_slice()).to_string())
     }
 
+    ///
+    /// Appends the import remappings and the import path options to the `solc` command.
+    ///
+    fn push_path_arguments(
+        command: &mut std::process::Command,
+        remappings: &[String],
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+    ) {
+        command.args(remappings);
+        if let Some(base_path) = base_path {
+            command.arg("--base-path");
+            command.arg(base_path);
+        }
+        for include_path in include_paths.into_iter() {
+            command.arg("--include-path");
+            command.arg(include_path);
+        }
+        if let Some(allow_paths) = allow_paths {
+            command.arg("--allow-paths");
+            command.arg(allow_paths);
+        }
+    }
+
     ///
     /// The `solc --version` mini-parser.
     ///
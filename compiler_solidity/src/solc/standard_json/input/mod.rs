@@ -38,6 +38,7 @@ impl Input {
         language: Language,
         paths: &[PathBuf],
         library_map: Vec<String>,
+        remappings: Vec<String>,
         output_selection: serde_json::Value,
         optimize: bool,
     ) -> anyhow::Result<Self> {
@@ -48,11 +49,17 @@ impl Input {
         }
 
         let libraries = Settings::parse_libraries(library_map)?;
+        let remappings = Settings::parse_remappings(remappings)?;
 
         Ok(Self {
             language,
             sources,
-            settings: Settings::new(libraries, output_selection, optimize),
+            settings: Settings::new(
+                libraries,
+                Some(remappings).filter(|remappings| !remappings.is_empty()),
+                output_selection,
+                optimize,
+            ),
         })
     }
 
@@ -75,7 +82,7 @@ impl Input {
         Ok(Self {
             language: Language::Solidity,
             sources,
-            settings: Settings::new(libraries, output_selection, optimize),
+            settings: Settings::new(libraries, None, output_selection, optimize),
         })
     }
 }
@@ -3,9 +3,12 @@
 //!
 
 pub mod optimizer;
+pub mod requested;
 pub mod selection;
+pub mod zksolc;
 
 use std::collections::BTreeMap;
+use std::path::Path;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -13,21 +16,47 @@ use serde::Serialize;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
 
 use self::optimizer::Optimizer;
+use self::requested::Requested;
 use self::selection::Selection;
+use self::zksolc::Zksolc;
 
 ///
 /// The `solc --standard-json` input settings representation.
 ///
+/// The settings which are not used by `zksolc` are preserved and passed to `solc` as is.
+///
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
+    /// The import remappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remappings: Option<Vec<String>>,
+    /// The target EVM version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
+    /// Whether to compile via the Yul IR.
+    #[serde(default, rename = "viaIR", skip_serializing_if = "Option::is_none")]
+    pub via_ir: Option<bool>,
     /// The linker library addresses.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub libraries: Option<BTreeMap<String, BTreeMap<String, String>>>,
     /// The output selection filters.
     pub output_selection: serde_json::Value,
     /// The optimizer settings.
+    #[serde(default)]
     pub optimizer: Optimizer,
+    /// The metadata settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// The SMTChecker settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_checker: Option<serde_json::Value>,
+    /// The `zksolc` settings, which are not passed to `solc`.
+    #[serde(default, skip_serializing)]
+    pub zksolc: Zksolc,
+    /// The other settings, which are passed to `solc` as is.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Settings {
@@ -36,13 +65,21 @@ impl Settings {
     ///
     pub fn new(
         libraries: BTreeMap<String, BTreeMap<String, String>>,
+        remappings: Option<Vec<String>>,
         output_selection: serde_json::Value,
         optimize: bool,
     ) -> Self {
         Self {
+            remappings,
+            evm_version: None,
+            via_ir: None,
             libraries: Some(libraries),
             output_selection,
             optimizer: Optimizer::new(optimize),
+            metadata: None,
+            model_checker: None,
+            zksolc: Zksolc::default(),
+            other: serde_json::Map::new(),
         }
     }
 
@@ -57,14 +94,7 @@ impl Settings {
             files.push("*".to_owned());
         }
 
-        let general_selections = vec![Selection::AST];
-        let per_contract_selections = vec![
-            Selection::ABI,
-            match pipeline {
-                SolcPipeline::Yul => Selection::Yul,
-                SolcPipeline::EVM => Selection::EVM,
-            },
-        ];
+        let (general_selections, per_contract_selections) = Self::get_selections(pipeline);
 
         let map = files
             .into_iter()
@@ -81,6 +111,65 @@ impl Settings {
         serde_json::Value::Object(map)
     }
 
+    ///
+    /// Merges the output selection required by `zksolc` into the one requested by the user.
+    ///
+    /// Returns the selection requested by the user, so the outputs added by `zksolc` can be
+    /// removed afterwards.
+    ///
+    pub fn merge_output_selection(
+        &mut self,
+        files: Vec<String>,
+        pipeline: SolcPipeline,
+    ) -> Requested {
+        let requested = Requested::new(&self.output_selection);
+
+        if !self.output_selection.is_object() {
+            self.output_selection = serde_json::Value::Object(serde_json::Map::new());
+        }
+        let user_files = self
+            .output_selection
+            .as_object_mut()
+            .expect("Always exists");
+        if let serde_json::Value::Object(files) = Self::get_output_selection(files, pipeline) {
+            for (file, contracts) in files.into_iter() {
+                let user_contracts = user_files
+                    .entry(file)
+                    .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+                if !user_contracts.is_object() {
+                    *user_contracts = serde_json::Value::Object(serde_json::Map::new());
+                }
+                let user_contracts = user_contracts.as_object_mut().expect("Always exists");
+
+                for (contract, selections) in contracts
+                    .as_object()
+                    .into_iter()
+                    .flat_map(|contracts| contracts.iter())
+                {
+                    let user_selections = user_contracts
+                        .entry(contract.to_owned())
+                        .or_insert_with(|| serde_json::Value::Array(vec![]));
+                    if !user_selections.is_array() {
+                        *user_selections = serde_json::Value::Array(vec![]);
+                    }
+                    let user_selections = user_selections.as_array_mut().expect("Always exists");
+
+                    for selection in selections
+                        .as_array()
+                        .into_iter()
+                        .flat_map(|selections| selections.iter())
+                    {
+                        if !user_selections.contains(selection) {
+                            user_selections.push(selection.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+
+        requested
+    }
+
     ///
     /// Generates the AST output selection pattern.
     ///
@@ -100,33 +189,293 @@ impl Settings {
         serde_json::Value::Object(map)
     }
 
+    ///
+    /// Returns the general and per-contract selections required by `zksolc`.
+    ///
+    fn get_selections(pipeline: SolcPipeline) -> (Vec<Selection>, Vec<Selection>) {
+        (
+            vec![Selection::AST],
+            vec![
+                Selection::ABI,
+                match pipeline {
+                    SolcPipeline::Yul => Selection::Yul,
+                    SolcPipeline::EVM => Selection::EVM,
+                },
+            ],
+        )
+    }
+
+    ///
+    /// Validates the `solc` import remappings, which have the `[context:]prefix=[target]` syntax.
+    ///
+    pub fn parse_remappings(input: Vec<String>) -> anyhow::Result<Vec<String>> {
+        for remapping in input.iter() {
+            let (context_and_prefix, _target) = remapping.split_once('=').ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The remapping `{}` is invalid: expected `[context:]prefix=[target]`",
+                    remapping
+                )
+            })?;
+            let prefix = context_and_prefix
+                .split_once(':')
+                .map(|(_context, prefix)| prefix)
+                .unwrap_or(context_and_prefix);
+            if prefix.is_empty() {
+                anyhow::bail!("The remapping `{}` prefix is empty", remapping);
+            }
+        }
+        Ok(input)
+    }
+
     ///
     /// Parses the library list and returns their double hashmap with path and name as keys.
     ///
+    /// Every argument is either a path to a file with the library list, a JSON object, or
+    /// a list of `<file>:<contract>=<address>` entries separated with commas or whitespaces.
+    /// The JSON object is either nested like the standard JSON `libraries` setting, or flat
+    /// with the `<file>:<contract>` keys. The files have the same format.
+    ///
     pub fn parse_libraries(
         input: Vec<String>,
     ) -> anyhow::Result<BTreeMap<String, BTreeMap<String, String>>> {
         let mut libraries = BTreeMap::new();
-        for (index, library) in input.into_iter().enumerate() {
-            let mut path_and_address = library.split('=');
-            let path = path_and_address
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("The library #{} path is missing", index))?;
-            let mut file_and_contract = path.split(':');
-            let file = file_and_contract
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("The library `{}` file name is missing", path))?;
-            let contract = file_and_contract.next().ok_or_else(|| {
-                anyhow::anyhow!("The library `{}` contract name is missing", path)
-            })?;
-            let address = path_and_address
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("The library `{}` address is missing", path))?;
-            libraries
+        for argument in input.into_iter() {
+            let argument = if Path::new(argument.as_str()).is_file() {
+                std::fs::read_to_string(argument.as_str()).map_err(|error| {
+                    anyhow::anyhow!("Library file `{}` reading error: {}", argument, error)
+                })?
+            } else {
+                argument
+            };
+
+            if argument.trim_start().starts_with('{') {
+                Self::parse_libraries_json(argument.as_str(), &mut libraries)?;
+                continue;
+            }
+
+            for library in argument
+                .split(|character: char| character == ',' || character.is_whitespace())
+                .filter(|library| !library.is_empty())
+            {
+                let (path, address) = library.split_once('=').ok_or_else(|| {
+                    anyhow::anyhow!("The library `{}` address is missing", library)
+                })?;
+                let (file, contract) = path.rsplit_once(':').ok_or_else(|| {
+                    anyhow::anyhow!("The library `{}` contract name is missing", library)
+                })?;
+                if file.is_empty() {
+                    anyhow::bail!("The library `{}` file name is missing", library);
+                }
+                Self::insert_library(&mut libraries, file, contract, address.to_owned())?;
+            }
+        }
+
+        Self::validate_libraries(&libraries)?;
+        Ok(libraries)
+    }
+
+    ///
+    /// Checks that every library address is a `0x`-prefixed 20-byte hexadecimal string.
+    ///
+    pub fn validate_libraries(
+        libraries: &BTreeMap<String, BTreeMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        for (file, contracts) in libraries.iter() {
+            for (contract, address) in contracts.iter() {
+                crate::linker::parse_address(format!("{}:{}", file, contract).as_str(), address)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Parses the nested or flat JSON object library list.
+    ///
+    fn parse_libraries_json(
+        input: &str,
+        libraries: &mut BTreeMap<String, BTreeMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let object: serde_json::Map<String, serde_json::Value> = serde_json::from_str(input)
+            .map_err(|error| anyhow::anyhow!("Library JSON parsing error: {}", error))?;
+
+        for (key, value) in object.into_iter() {
+            match value {
+                serde_json::Value::String(address) => {
+                    let (file, contract) = key.rsplit_once(':').ok_or_else(|| {
+                        anyhow::anyhow!("The library `{}` contract name is missing", key)
+                    })?;
+                    Self::insert_library(libraries, file, contract, address)?;
+                }
+                serde_json::Value::Object(contracts) => {
+                    for (contract, address) in contracts.into_iter() {
+                        let address = address.as_str().map(str::to_owned).ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "The library `{}:{}` address must be a string",
+                                key,
+                                contract
+                            )
+                        })?;
+                        Self::insert_library(libraries, key.as_str(), contract.as_str(), address)?;
+                    }
+                }
+                _ => anyhow::bail!(
+                    "The library `{}` must be either an address or an object of addresses",
+                    key
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Inserts the library address, checking that it is not specified with a different one.
+    ///
+    fn insert_library(
+        libraries: &mut BTreeMap<String, BTreeMap<String, String>>,
+        file: &str,
+        contract: &str,
+        address: String,
+    ) -> anyhow::Result<()> {
+        let contracts = libraries
+            .entry(file.to_owned())
+            .or_insert_with(BTreeMap::new);
+        match contracts.get(contract) {
+            Some(existing) if !existing.eq_ignore_ascii_case(address.as_str()) => anyhow::bail!(
+                "The library `{}:{}` is specified with different addresses `{}` and `{}`",
+                file,
+                contract,
+                existing,
+                address
+            ),
+            Some(_) => {}
+            None => {
+                contracts.insert(contract.to_owned(), address);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Settings;
+
+    const ADDRESS_1: &str = "0x1234567890123456789012345678901234567890";
+    const ADDRESS_2: &str = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd";
+
+    #[test]
+    fn remappings() {
+        let remappings = vec![
+            "@openzeppelin/=lib/openzeppelin/".to_owned(),
+            "context:prefix=target".to_owned(),
+            "prefix=".to_owned(),
+        ];
+        assert_eq!(
+            Settings::parse_remappings(remappings.clone()).expect("Always valid"),
+            remappings
+        );
+    }
+
+    #[test]
+    fn remappings_malformed() {
+        for remapping in ["prefix", "=target", "context:=target", ""] {
+            assert!(
+                Settings::parse_remappings(vec![remapping.to_owned()]).is_err(),
+                "`{}` must be rejected",
+                remapping
+            );
+        }
+    }
+
+    #[test]
+    fn libraries() {
+        let libraries = Settings::parse_libraries(vec![
+            format!("a.sol:A={}, b/c.sol:B={}", ADDRESS_1, ADDRESS_2),
+            format!(
+                r#"{{ "d.sol": {{ "D": "{}" }}, "e.sol:E": "{}" }}"#,
+                ADDRESS_1, ADDRESS_2
+            ),
+            format!("a.sol:A={}", ADDRESS_1.to_uppercase().replace("0X", "0x")),
+        ])
+        .expect("Always valid");
+
+        let mut expected = BTreeMap::new();
+        for (file, contract, address) in [
+            ("a.sol", "A", ADDRESS_1),
+            ("b/c.sol", "B", ADDRESS_2),
+            ("d.sol", "D", ADDRESS_1),
+            ("e.sol", "E", ADDRESS_2),
+        ] {
+            expected
                 .entry(file.to_owned())
                 .or_insert_with(BTreeMap::new)
                 .insert(contract.to_owned(), address.to_owned());
         }
-        Ok(libraries)
+        assert_eq!(libraries, expected);
+    }
+
+    #[test]
+    fn libraries_file() {
+        let path =
+            std::env::temp_dir().join(format!("zksolc-libraries-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            format!("a.sol:A={}\nb.sol:B={}\n", ADDRESS_1, ADDRESS_2),
+        )
+        .expect("Always valid");
+        let libraries = Settings::parse_libraries(vec![path.to_string_lossy().to_string()]);
+        std::fs::remove_file(&path).expect("Always valid");
+
+        let libraries = libraries.expect("Always valid");
+        assert_eq!(libraries["a.sol"]["A"], ADDRESS_1);
+        assert_eq!(libraries["b.sol"]["B"], ADDRESS_2);
+    }
+
+    #[test]
+    fn libraries_invalid_address() {
+        for address in [
+            "1234567890123456789012345678901234567890",
+            "0x123456789012345678901234567890123456789",
+            "0x12345678901234567890123456789012345678901",
+            "0x123456789012345678901234567890123456789g",
+            "",
+        ] {
+            assert!(
+                Settings::parse_libraries(vec![format!("a.sol:A={}", address)]).is_err(),
+                "`{}` must be rejected",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn libraries_malformed() {
+        for library in [
+            "a.sol:A".to_owned(),
+            format!("A={}", ADDRESS_1),
+            format!(":A={}", ADDRESS_1),
+            format!(r#"{{ "a.sol": 1 }}"#),
+            format!(r#"{{ "a.sol": {{ "A": 1 }} }}"#),
+            format!(r#"{{ "A": "{}" }}"#, ADDRESS_1),
+        ] {
+            assert!(
+                Settings::parse_libraries(vec![library.clone()]).is_err(),
+                "`{}` must be rejected",
+                library
+            );
+        }
+    }
+
+    #[test]
+    fn libraries_duplicate() {
+        let error = Settings::parse_libraries(vec![
+            format!("a.sol:A={}", ADDRESS_1),
+            format!(r#"{{ "a.sol": {{ "A": "{}" }} }}"#, ADDRESS_2),
+        ])
+        .expect_err("Must be rejected");
+        assert!(error.to_string().contains("different addresses"));
     }
 }
@@ -8,11 +8,23 @@ use serde::Serialize;
 ///
 /// The `solc --standard-json` input settings optimizer representation.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+/// All fields are optional like in `solc`, and the unknown ones are passed to `solc` as is.
+///
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Optimizer {
     /// Whether the optimizer is enabled.
+    #[serde(default)]
     pub enabled: bool,
+    /// The number of runs the `solc` optimizer is tuned for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<usize>,
+    /// The `solc` optimizer step switches, which are passed as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+    /// The other optimizer settings, which are passed to `solc` as is.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Optimizer {
@@ -20,6 +32,51 @@ impl Optimizer {
     /// A shortcut constructor.
     ///
     pub fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            runs: None,
+            details: None,
+            other: serde_json::Map::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solc::standard_json::input::settings::Settings;
+
+    use super::Optimizer;
+
+    #[test]
+    fn round_trip() {
+        let json = serde_json::json!({
+            "enabled": true,
+            "runs": 200,
+            "details": {
+                "yul": true,
+                "yulDetails": { "stackAllocation": true }
+            },
+            "unknown": { "key": [1, 2, 3] }
+        });
+
+        let optimizer: Optimizer = serde_json::from_value(json.clone()).expect("Always valid");
+        assert!(optimizer.enabled);
+        assert_eq!(optimizer.runs, Some(200));
+        assert_eq!(
+            serde_json::to_value(&optimizer).expect("Always valid"),
+            json
+        );
+    }
+
+    #[test]
+    fn optional() {
+        let optimizer: Optimizer =
+            serde_json::from_value(serde_json::json!({ "runs": 1 })).expect("Always valid");
+        assert!(!optimizer.enabled);
+
+        let settings: Settings =
+            serde_json::from_value(serde_json::json!({ "outputSelection": {} }))
+                .expect("Always valid");
+        assert!(!settings.optimizer.enabled);
     }
 }
@@ -0,0 +1,76 @@
+//!
+//! The `solc --standard-json` output selection requested by the user.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use super::selection::Selection;
+
+///
+/// The `solc --standard-json` output selection requested by the user.
+///
+/// Saved before the `zksolc` selections are merged in, so the outputs added by `zksolc` for its
+/// own needs can be removed per file and contract afterwards.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Requested {
+    /// The requested output names, grouped by the file and contract patterns.
+    files: BTreeMap<String, BTreeMap<String, HashSet<String>>>,
+}
+
+impl Requested {
+    /// The file and contract wildcard.
+    pub const WILDCARD: &'static str = "*";
+
+    /// The contract key of the file-level outputs, such as the AST.
+    pub const FILE_LEVEL: &'static str = "";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(output_selection: &serde_json::Value) -> Self {
+        let files = output_selection
+            .as_object()
+            .into_iter()
+            .flat_map(|files| files.iter())
+            .map(|(file, contracts)| {
+                let contracts = contracts
+                    .as_object()
+                    .into_iter()
+                    .flat_map(|contracts| contracts.iter())
+                    .map(|(contract, selections)| {
+                        let selections = selections
+                            .as_array()
+                            .into_iter()
+                            .flat_map(|selections| selections.iter())
+                            .filter_map(serde_json::Value::as_str)
+                            .map(str::to_owned)
+                            .collect();
+                        (contract.to_owned(), selections)
+                    })
+                    .collect();
+                (file.to_owned(), contracts)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    ///
+    /// Checks whether the `selection` has been requested for the `contract` in the `file`.
+    ///
+    /// The file-level selections are checked with the empty contract name.
+    ///
+    pub fn is_requested(&self, file: &str, contract: &str, selection: Selection) -> bool {
+        self.files
+            .iter()
+            .filter(|(pattern, _)| pattern.as_str() == Self::WILDCARD || pattern.as_str() == file)
+            .flat_map(|(_, contracts)| contracts.iter())
+            .filter(|(pattern, _)| {
+                pattern.as_str() == contract
+                    || (contract != Self::FILE_LEVEL && pattern.as_str() == Self::WILDCARD)
+            })
+            .any(|(_, selections)| selection.is_requested(selections))
+    }
+}
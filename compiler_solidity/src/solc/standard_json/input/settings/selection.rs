@@ -2,6 +2,8 @@
 //! The `solc --standard-json` expected output selection.
 //!
 
+use std::collections::HashSet;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -26,6 +28,21 @@ pub enum Selection {
     EVM,
 }
 
+impl Selection {
+    ///
+    /// Checks whether the selection is covered by the user-requested ones, including
+    /// the `*` and parent wildcards like `evm`.
+    ///
+    pub fn is_requested(&self, requested: &HashSet<String>) -> bool {
+        let name = self.to_string();
+        requested.iter().any(|requested| {
+            requested.as_str() == "*"
+                || requested.as_str() == name.as_str()
+                || name.starts_with(format!("{}.", requested).as_str())
+        })
+    }
+}
+
 impl std::fmt::Display for Selection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,36 @@
+//!
+//! The `zksolc` settings, embedded into the `solc --standard-json` input settings.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The `zksolc` settings, embedded into the `solc --standard-json` input settings.
+///
+/// Taken from the `settings.zksolc` object, which is never passed to `solc`, since the latter
+/// rejects unknown settings.
+///
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zksolc {
+    /// Whether the LLVM optimizer is enabled. Defaults to the `solc` optimizer flag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimize: Option<bool>,
+    /// Whether to embed the `zksolc` metadata hash into the bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_hash: Option<bool>,
+    /// The bytecode size limit in words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_size_limit: Option<usize>,
+    /// Whether exceeding the bytecode size limit is an error rather than a warning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_size_limit_error: Option<bool>,
+    /// The maximum number of clones of a single block in the Ethereal IR functions.
+    #[serde(
+        default,
+        rename = "ethirCloneLimit",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ethereal_ir_clone_limit: Option<usize>,
+}
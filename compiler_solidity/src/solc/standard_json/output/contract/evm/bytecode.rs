@@ -2,16 +2,24 @@
 //! The `solc --standard-json` output contract EVM bytecode.
 //!
 
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::linker::References as LinkReferences;
+
 ///
 /// The `solc --standard-json` output contract EVM bytecode.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Bytecode {
     /// The bytecode object.
     pub object: String,
+    /// The placeholders of the unlinked libraries.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub link_references: LinkReferences,
 }
 
 impl Bytecode {
@@ -19,6 +27,9 @@ impl Bytecode {
     /// A shortcut constructor.
     ///
     pub fn new(object: String) -> Self {
-        Self { object }
+        Self {
+            object,
+            link_references: LinkReferences::new(),
+        }
     }
 }
@@ -4,6 +4,8 @@
 
 pub mod bytecode;
 
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -15,13 +17,20 @@ use self::bytecode::Bytecode;
 /// The `solc --standard-json` output contract EVM data.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EVM {
     /// The contract assembly code.
-    #[serde(rename = "legacyAssembly")]
+    #[serde(rename = "legacyAssembly", skip_serializing_if = "Option::is_none")]
     pub assembly: Option<Assembly>,
     /// The contract bytecode.
     /// Is reset by that of zkEVM before yielding the compiled project artifacts.
     pub bytecode: Option<Bytecode>,
+    /// The contract function signatures.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method_identifiers: Option<BTreeMap<String, String>>,
+    /// The other outputs requested by the user, which are returned as is.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 impl EVM {
@@ -32,6 +41,8 @@ impl EVM {
         Self {
             assembly: None,
             bytecode: Some(Bytecode::new(bytecode)),
+            method_identifiers: None,
+            other: serde_json::Map::new(),
         }
     }
 }
@@ -23,6 +23,18 @@ pub struct Contract {
     /// The contract ABI representation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub abi: Option<serde_json::Value>,
+    /// The contract metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// The contract developer documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<serde_json::Value>,
+    /// The contract user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<serde_json::Value>,
+    /// The contract storage layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_layout: Option<serde_json::Value>,
     /// Contract's bytecode and related objects
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub evm: Option<EVM>,
@@ -32,4 +44,13 @@ pub struct Contract {
     /// The contract's zkEVM bytecode hash.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
+    /// The contract's zkEVM bytecode size in words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    /// The `zksolc` metadata, whose hash is embedded into the bytecode if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zksolc_metadata: Option<serde_json::Value>,
+    /// The other outputs requested by the user, which are returned as is.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
@@ -21,6 +21,15 @@ pub struct SourceLocation {
     pub end: isize,
 }
 
+impl SourceLocation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: String, start: isize, end: isize) -> Self {
+        Self { file, start, end }
+    }
+}
+
 impl FromStr for SourceLocation {
     type Err = anyhow::Error;
 
@@ -45,3 +54,9 @@ impl FromStr for SourceLocation {
         })
     }
 }
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.start, self.end)
+    }
+}
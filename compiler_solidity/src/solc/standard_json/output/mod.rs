@@ -7,22 +7,31 @@ pub mod error;
 pub mod source;
 
 use std::collections::BTreeMap;
+use std::time::Instant;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::list::List as DiagnosticList;
+use crate::diagnostic::Diagnostic;
 use crate::dump_flag::DumpFlag;
 use crate::evm::assembly::instruction::Instruction;
 use crate::evm::assembly::Assembly;
 use crate::project::contract::source::Source as ProjectContractSource;
+use crate::project::contract::statistics::Statistics as ProjectContractStatistics;
 use crate::project::contract::Contract as ProjectContract;
 use crate::project::Project;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::requested::Requested as SolcStandardJsonInputSettingsRequested;
+use crate::solc::standard_json::input::settings::selection::Selection as SolcStandardJsonInputSettingsSelection;
+use crate::yul::analyzer::Analyzer;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::Contract;
 use self::error::Error as SolcStandardJsonOutputError;
+use self::source::ast::AST;
 use self::source::Source;
 
 ///
@@ -56,7 +65,6 @@ impl Output {
         if let SolcPipeline::EVM = pipeline {
             self.preprocess_dependencies()?;
         }
-        self.sources = None;
 
         let files = match self.contracts.as_mut() {
             Some(files) => files,
@@ -71,14 +79,16 @@ impl Output {
             }
         };
         let mut project_contracts = BTreeMap::new();
+        let mut diagnostics = Vec::new();
 
         for (path, contracts) in files.iter_mut() {
             for (name, contract) in contracts.iter_mut() {
                 let full_path = format!("{}:{}", path, name);
 
+                let mut parse_time = None;
                 let source = match pipeline {
                     SolcPipeline::Yul => {
-                        let ir_optimized = match contract.ir_optimized.take() {
+                        let ir_optimized = match contract.ir_optimized.to_owned() {
                             Some(ir_optimized) => ir_optimized,
                             None => continue,
                         };
@@ -86,16 +96,39 @@ impl Output {
                             continue;
                         }
 
+                        let parse_start = Instant::now();
+                        let mut lexer = Lexer::new(ir_optimized.clone());
+                        let object = match Object::parse(&mut lexer, None) {
+                            Ok(object) => object,
+                            Err(error) => {
+                                diagnostics.push(
+                                    Diagnostic::new_error(
+                                        DiagnosticCode::YulParsing,
+                                        error.to_string(),
+                                    )
+                                    .with_contract_path(full_path.as_str()),
+                                );
+                                continue;
+                            }
+                        };
+                        if let Err(error) = Analyzer::analyze(&object) {
+                            diagnostics.extend(
+                                DiagnosticList::from_error(
+                                    error,
+                                    DiagnosticCode::YulAnalysis,
+                                    Some(full_path.as_str()),
+                                )
+                                .diagnostics,
+                            );
+                            continue;
+                        }
+
                         if dump_flags.contains(&DumpFlag::Yul) {
                             eprintln!("Contract `{}` Yul:\n", full_path);
-                            println!("{}", ir_optimized);
+                            println!("{}", object);
                         }
 
-                        let mut lexer = Lexer::new(ir_optimized.clone());
-                        let object = Object::parse(&mut lexer, None).map_err(|error| {
-                            anyhow::anyhow!("Contract `{}` parsing error: {:?}", full_path, error)
-                        })?;
-
+                        parse_time = Some(parse_start.elapsed());
                         ProjectContractSource::new_yul(ir_optimized, object)
                     }
                     SolcPipeline::EVM => {
@@ -109,15 +142,75 @@ impl Output {
                     }
                 };
 
-                let project_contract =
-                    ProjectContract::new(full_path.clone(), source, contract.abi.take());
+                let mut project_contract =
+                    ProjectContract::new(full_path.clone(), source, contract.abi.to_owned());
+                project_contract.statistics.parse_time_ms =
+                    parse_time.map(ProjectContractStatistics::milliseconds);
                 project_contracts.insert(full_path, project_contract);
             }
         }
 
+        if !diagnostics.is_empty() {
+            return Err(DiagnosticList::new(diagnostics).into());
+        }
+
         Ok(Project::new(version, project_contracts, libraries))
     }
 
+    ///
+    /// Removes the outputs which have been requested by `zksolc` for its own needs.
+    ///
+    /// Each output is kept if the user has requested it for its file and contract.
+    ///
+    pub fn remove_selections(&mut self, requested: &SolcStandardJsonInputSettingsRequested) {
+        for (file, source) in self
+            .sources
+            .iter_mut()
+            .flat_map(|sources| sources.iter_mut())
+        {
+            if !requested.is_requested(
+                file.as_str(),
+                SolcStandardJsonInputSettingsRequested::FILE_LEVEL,
+                SolcStandardJsonInputSettingsSelection::AST,
+            ) {
+                source.ast = None;
+            }
+        }
+
+        for (file, contracts) in self.contracts.iter_mut().flat_map(|files| files.iter_mut()) {
+            for (name, contract) in contracts.iter_mut() {
+                let is_requested =
+                    |selection| requested.is_requested(file.as_str(), name.as_str(), selection);
+                if !is_requested(SolcStandardJsonInputSettingsSelection::ABI) {
+                    contract.abi = None;
+                }
+                if !is_requested(SolcStandardJsonInputSettingsSelection::Yul) {
+                    contract.ir_optimized = None;
+                }
+                if !is_requested(SolcStandardJsonInputSettingsSelection::EVM) {
+                    if let Some(evm) = contract.evm.as_mut() {
+                        evm.assembly = None;
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Appends the `zksolc` error to the `solc` errors and warnings.
+    ///
+    /// The error is split into diagnostics, which are converted into the `solc` error format.
+    ///
+    pub fn push_error(&mut self, error: anyhow::Error) {
+        let diagnostics = DiagnosticList::from_error(error, DiagnosticCode::CodeGeneration, None);
+        self.errors.get_or_insert_with(Vec::new).extend(
+            diagnostics
+                .diagnostics
+                .into_iter()
+                .map(SolcStandardJsonOutputError::from),
+        );
+    }
+
     ///
     /// The pass, which replaces with dependency indexes with actual data.
     ///
@@ -213,6 +306,7 @@ impl Output {
         let mut messages = Vec::new();
         for (path, source) in sources.iter() {
             if let Some(ast) = source.ast.as_ref() {
+                let ast = AST::deserialize(ast)?;
                 let mut warnings = ast.get_warnings()?;
                 for warning in warnings.iter_mut() {
                     warning.push_contract_path(path.as_str());
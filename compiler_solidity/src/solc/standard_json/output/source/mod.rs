@@ -7,8 +7,6 @@ pub mod ast;
 use serde::Deserialize;
 use serde::Serialize;
 
-use self::ast::AST;
-
 ///
 /// The `solc --standard-json` output source.
 ///
@@ -18,5 +16,9 @@ pub struct Source {
     /// The source code ID.
     pub id: usize,
     /// The source code AST.
-    pub ast: Option<AST>,
+    ///
+    /// Kept as is to be returned to the user, since only a part of it is deserialized by
+    /// the `zksolc` checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ast: Option<serde_json::Value>,
 }
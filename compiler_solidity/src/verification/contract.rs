@@ -0,0 +1,29 @@
+//!
+//! The verification artifact contract.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::project::metadata::Metadata;
+
+///
+/// The verification artifact contract.
+///
+/// Has the same fields as the `zksolc --standard-json` output contract, so they can be copied
+/// from there as is.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The expected bytecode hash.
+    pub hash: String,
+    /// The expected factory dependencies, where the keys are the hashes and the values are paths.
+    #[serde(default)]
+    pub factory_dependencies: BTreeMap<String, String>,
+    /// The `zksolc` metadata, which describes the build settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zksolc_metadata: Option<Metadata>,
+}
@@ -0,0 +1,165 @@
+//!
+//! The source code verification.
+//!
+
+pub mod contract;
+pub mod report;
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::build::Build;
+use crate::project::metadata::Metadata;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::Input as SolcStandardJsonInput;
+use crate::solc::Compiler as SolcCompiler;
+
+use self::contract::Contract;
+use self::report::Report;
+
+///
+/// The verification artifact.
+///
+/// Contains the standard JSON input the contracts have been compiled from, and their
+/// expected hashes.
+///
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Artifact {
+    /// The standard JSON input.
+    pub input: SolcStandardJsonInput,
+    /// The `solc` pipeline the contracts have been compiled with.
+    ///
+    /// If unset, it is taken from the contract metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline: Option<SolcPipeline>,
+    /// The expected contracts, where the keys are the full contract paths.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl Artifact {
+    ///
+    /// Reads the artifact from the JSON file.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path).map_err(|error| {
+            anyhow::anyhow!("Verification artifact {:?} reading error: {}", path, error)
+        })?;
+        serde_json::from_reader(std::io::BufReader::new(file)).map_err(|error| {
+            anyhow::anyhow!("Verification artifact {:?} parsing error: {}", path, error)
+        })
+    }
+
+    ///
+    /// Returns the `solc` pipeline the contracts have been compiled with.
+    ///
+    /// It is taken from the artifact or the contract metadata, and all contracts must agree.
+    /// If neither is set, the `default` one is used.
+    ///
+    pub fn pipeline(&self, default: SolcPipeline) -> anyhow::Result<SolcPipeline> {
+        if let Some(pipeline) = self.pipeline {
+            return Ok(pipeline);
+        }
+
+        let pipelines: HashSet<SolcPipeline> = self
+            .contracts
+            .values()
+            .filter_map(|contract| contract.zksolc_metadata.as_ref())
+            .map(|metadata| metadata.pipeline)
+            .collect();
+        if pipelines.len() > 1 {
+            anyhow::bail!("The contracts have been compiled with different pipelines");
+        }
+
+        Ok(self
+            .contracts
+            .values()
+            .find_map(|contract| contract.zksolc_metadata.as_ref())
+            .map(|metadata| metadata.pipeline)
+            .unwrap_or(default))
+    }
+
+    ///
+    /// Recompiles the input with the same settings and compares the result with
+    /// the expected contracts.
+    ///
+    /// The `settings.zksolc` options are applied the same way as in the standard JSON mode,
+    /// with `ethereal_ir_clone_limit` used if the input does not specify one.
+    ///
+    pub fn verify(
+        self,
+        solc: &SolcCompiler,
+        default_pipeline: SolcPipeline,
+        ethereal_ir_clone_limit: Option<usize>,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+    ) -> anyhow::Result<Report> {
+        let solc_version = solc.version()?;
+        let pipeline = self.pipeline(default_pipeline)?;
+        if pipeline == SolcPipeline::Yul && solc_version < SolcCompiler::FIRST_YUL_VERSION {
+            anyhow::bail!(
+                "The Yul pipeline requires solc >={}, found {}",
+                SolcCompiler::FIRST_YUL_VERSION,
+                solc_version
+            );
+        }
+
+        let mut input = self.input;
+        input.settings.merge_output_selection(vec![], pipeline);
+
+        let libraries = input.settings.libraries.clone().unwrap_or_default();
+        let optimize = input
+            .settings
+            .zksolc
+            .optimize
+            .unwrap_or(input.settings.optimizer.enabled);
+        let metadata_hash = input.settings.zksolc.metadata_hash.unwrap_or_default();
+        let ethereal_ir_clone_limit = input
+            .settings
+            .zksolc
+            .ethereal_ir_clone_limit
+            .or(ethereal_ir_clone_limit);
+        let bytecode_size_limit = input
+            .settings
+            .zksolc
+            .bytecode_size_limit
+            .unwrap_or(Build::BYTECODE_SIZE_LIMIT_DEFAULT);
+        let bytecode_size_limit_error = input
+            .settings
+            .zksolc
+            .bytecode_size_limit_error
+            .unwrap_or_default();
+        let metadata = Metadata::new_from_input(&solc_version, pipeline, optimize, &input);
+
+        let mut output = solc.standard_json(input, base_path, include_paths, allow_paths)?;
+        let errors: Vec<String> = output
+            .errors
+            .iter()
+            .flatten()
+            .filter(|error| error.severity.as_str() == "error")
+            .map(|error| error.to_string())
+            .collect();
+        if !errors.is_empty() {
+            anyhow::bail!("{}", errors.join("\n"));
+        }
+
+        let optimizer_settings = if optimize {
+            compiler_llvm_context::OptimizerSettings::cycles()
+        } else {
+            compiler_llvm_context::OptimizerSettings::none()
+        };
+        let mut project = output.try_to_project(libraries, pipeline, solc_version, &[])?;
+        project.set_metadata(metadata, metadata_hash);
+        project.set_ethereal_ir_clone_limit(ethereal_ir_clone_limit);
+        let mut build = project.compile_all(optimizer_settings, vec![])?;
+        build.check_bytecode_size(bytecode_size_limit, bytecode_size_limit_error);
+        let build = build.check_errors()?;
+
+        Ok(Report::new(self.contracts, &build))
+    }
+}
@@ -0,0 +1,123 @@
+//!
+//! The verification difference.
+//!
+
+///
+/// The verification difference.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// The contract has not been found in the build.
+    Missing,
+    /// The bytecode hash is different.
+    Hash {
+        /// The expected hash.
+        expected: String,
+        /// The actual hash.
+        found: String,
+    },
+    /// The factory dependency hash is different.
+    DependencyHash {
+        /// The dependency path.
+        path: String,
+        /// The expected hash.
+        expected: String,
+        /// The actual hash.
+        found: String,
+    },
+    /// The expected factory dependency has not been found.
+    DependencyMissing {
+        /// The dependency path.
+        path: String,
+        /// The expected hash.
+        expected: String,
+    },
+    /// The factory dependency has not been expected.
+    DependencyUnexpected {
+        /// The dependency path.
+        path: String,
+        /// The actual hash.
+        found: String,
+    },
+    /// The `zksolc` metadata compiler version or setting is different.
+    Setting {
+        /// The metadata field name.
+        field: String,
+        /// The expected JSON value.
+        expected: String,
+        /// The actual JSON value.
+        found: String,
+    },
+    /// The `zksolc` metadata source file hash is different.
+    Source {
+        /// The source file path.
+        path: String,
+        /// The expected hash, which is `None` if the file has not been expected.
+        expected: Option<String>,
+        /// The actual hash, which is `None` if the file is missing.
+        found: Option<String>,
+    },
+}
+
+impl std::fmt::Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "the contract is missing from the build"),
+            Self::Hash { expected, found } => {
+                write!(f, "hash: expected `{}`, found `{}`", expected, found)
+            }
+            Self::DependencyHash {
+                path,
+                expected,
+                found,
+            } => write!(
+                f,
+                "factory dependency `{}` hash: expected `{}`, found `{}`",
+                path, expected, found
+            ),
+            Self::DependencyMissing { path, expected } => write!(
+                f,
+                "factory dependency `{}` with hash `{}` is missing",
+                path, expected
+            ),
+            Self::DependencyUnexpected { path, found } => write!(
+                f,
+                "factory dependency `{}` with hash `{}` is unexpected",
+                path, found
+            ),
+            Self::Setting {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "metadata `{}`: expected `{}`, found `{}`",
+                field, expected, found
+            ),
+            Self::Source {
+                path,
+                expected: Some(expected),
+                found: Some(found),
+            } => write!(
+                f,
+                "source `{}` hash: expected `{}`, found `{}`",
+                path, expected, found
+            ),
+            Self::Source {
+                path,
+                expected: Some(expected),
+                found: None,
+            } => write!(f, "source `{}` with hash `{}` is missing", path, expected),
+            Self::Source {
+                path,
+                expected: None,
+                found,
+            } => write!(
+                f,
+                "source `{}` with hash `{}` is unexpected",
+                path,
+                found.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
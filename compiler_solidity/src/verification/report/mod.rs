@@ -0,0 +1,277 @@
+//!
+//! The verification report.
+//!
+
+pub mod difference;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::build::Build;
+use crate::project::metadata::Metadata;
+
+use super::contract::Contract;
+
+use self::difference::Difference;
+
+///
+/// The verification report.
+///
+#[derive(Debug, Default)]
+pub struct Report {
+    /// The contracts which match the artifact.
+    pub matched: Vec<String>,
+    /// The contracts which differ from the artifact, along with the differences.
+    pub mismatched: BTreeMap<String, Vec<Difference>>,
+}
+
+impl Report {
+    /// The metadata field with the source hashes, which are compared file by file.
+    const METADATA_SOURCE_HASHES_FIELD: &'static str = "sourceHashes";
+
+    ///
+    /// Compares the build with the expected contracts.
+    ///
+    /// The factory dependencies are compared by their paths, so a changed dependency is shown
+    /// as a single hash difference.
+    ///
+    /// If a contract does not match and has the `zksolc` metadata, its compiler versions,
+    /// settings, and source hashes are compared with the rebuilt ones, so the setting mismatches
+    /// can be told apart from the source code differences.
+    ///
+    pub fn new(expected: BTreeMap<String, Contract>, build: &Build) -> Self {
+        let mut report = Self::default();
+
+        for (path, expected) in expected.into_iter() {
+            let contract = match build.contracts.get(path.as_str()) {
+                Some(contract) => contract,
+                None => {
+                    report.mismatched.insert(path, vec![Difference::Missing]);
+                    continue;
+                }
+            };
+
+            let mut differences = Vec::new();
+            if contract.build.hash != expected.hash {
+                differences.push(Difference::Hash {
+                    expected: expected.hash,
+                    found: contract.build.hash.to_owned(),
+                });
+            }
+
+            let mut found_dependencies: BTreeMap<&str, &str> = contract
+                .build
+                .factory_dependencies
+                .iter()
+                .map(|(hash, path)| (path.as_str(), hash.as_str()))
+                .collect();
+            for (expected_hash, dependency_path) in expected.factory_dependencies.into_iter() {
+                match found_dependencies.remove(dependency_path.as_str()) {
+                    Some(found_hash) if found_hash == expected_hash.as_str() => {}
+                    Some(found_hash) => differences.push(Difference::DependencyHash {
+                        path: dependency_path,
+                        expected: expected_hash,
+                        found: found_hash.to_owned(),
+                    }),
+                    None => differences.push(Difference::DependencyMissing {
+                        path: dependency_path,
+                        expected: expected_hash,
+                    }),
+                }
+            }
+            for (dependency_path, found_hash) in found_dependencies.into_iter() {
+                differences.push(Difference::DependencyUnexpected {
+                    path: dependency_path.to_owned(),
+                    found: found_hash.to_owned(),
+                });
+            }
+
+            if let (false, Some(expected), Some(found)) = (
+                differences.is_empty(),
+                expected.zksolc_metadata.as_ref(),
+                build.metadata.as_ref(),
+            ) {
+                differences.extend(Self::metadata_differences(expected, found));
+            }
+
+            if differences.is_empty() {
+                report.matched.push(path);
+            } else {
+                report.mismatched.insert(path, differences);
+            }
+        }
+
+        report
+    }
+
+    ///
+    /// Compares the `zksolc` metadata field by field, and the source hashes file by file.
+    ///
+    fn metadata_differences(expected: &Metadata, found: &Metadata) -> Vec<Difference> {
+        let mut differences = Vec::new();
+
+        let expected_json = serde_json::to_value(expected).expect("Always valid");
+        let found_json = serde_json::to_value(found).expect("Always valid");
+        let fields: BTreeSet<&String> = expected_json
+            .as_object()
+            .into_iter()
+            .chain(found_json.as_object())
+            .flat_map(|object| object.keys())
+            .filter(|field| field.as_str() != Self::METADATA_SOURCE_HASHES_FIELD)
+            .collect();
+        for field in fields.into_iter() {
+            let expected = expected_json.get(field.as_str());
+            let found = found_json.get(field.as_str());
+            if expected != found {
+                differences.push(Difference::Setting {
+                    field: field.to_owned(),
+                    expected: expected.map(|value| value.to_string()).unwrap_or_default(),
+                    found: found.map(|value| value.to_string()).unwrap_or_default(),
+                });
+            }
+        }
+
+        let paths: BTreeSet<&String> = expected
+            .source_hashes
+            .keys()
+            .chain(found.source_hashes.keys())
+            .collect();
+        for path in paths.into_iter() {
+            let expected = expected.source_hashes.get(path);
+            let found = found.source_hashes.get(path);
+            if expected != found {
+                differences.push(Difference::Source {
+                    path: path.to_owned(),
+                    expected: expected.cloned(),
+                    found: found.cloned(),
+                });
+            }
+        }
+
+        differences
+    }
+
+    ///
+    /// Whether all contracts match the artifact.
+    ///
+    pub fn is_success(&self) -> bool {
+        self.mismatched.is_empty()
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for path in self.matched.iter() {
+            writeln!(f, "Contract `{}`: OK", path)?;
+        }
+        for (path, differences) in self.mismatched.iter() {
+            writeln!(f, "Contract `{}`: MISMATCH", path)?;
+            for difference in differences.iter() {
+                writeln!(f, "    {}", difference)?;
+            }
+            if differences.iter().any(|difference| {
+                matches!(
+                    difference,
+                    Difference::DependencyHash { .. }
+                        | Difference::DependencyMissing { .. }
+                        | Difference::DependencyUnexpected { .. }
+                )
+            }) {
+                writeln!(
+                    f,
+                    "    the hash difference may be caused by the factory dependencies"
+                )?;
+            }
+            if differences
+                .iter()
+                .any(|difference| matches!(difference, Difference::Setting { .. }))
+            {
+                writeln!(
+                    f,
+                    "    the hash difference may be caused by the compiler versions or settings"
+                )?;
+            }
+            if differences
+                .iter()
+                .any(|difference| matches!(difference, Difference::Source { .. }))
+            {
+                writeln!(
+                    f,
+                    "    the hash difference may be caused by the source code changes"
+                )?;
+            }
+        }
+
+        if self.matched.is_empty() && self.mismatched.len() > 1 {
+            writeln!(
+                f,
+                "None of the contracts match, which usually means that the compiler versions or settings are different"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::project::metadata::Metadata;
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+
+    use super::Difference;
+    use super::Report;
+
+    #[test]
+    fn metadata_differences() {
+        let expected = Metadata::new(
+            &semver::Version::new(0, 8, 15),
+            SolcPipeline::Yul,
+            true,
+            Some(serde_json::json!({ "enabled": true })),
+            BTreeMap::from([
+                ("A.sol".to_owned(), "aa".to_owned()),
+                ("B.sol".to_owned(), "bb".to_owned()),
+            ]),
+        );
+        let found = Metadata::new(
+            &semver::Version::new(0, 8, 14),
+            SolcPipeline::Yul,
+            false,
+            Some(serde_json::json!({ "enabled": true })),
+            BTreeMap::from([
+                ("A.sol".to_owned(), "aa".to_owned()),
+                ("B.sol".to_owned(), "b0".to_owned()),
+                ("C.sol".to_owned(), "cc".to_owned()),
+            ]),
+        );
+
+        assert_eq!(
+            Report::metadata_differences(&expected, &found),
+            vec![
+                Difference::Setting {
+                    field: "optimize".to_owned(),
+                    expected: "true".to_owned(),
+                    found: "false".to_owned(),
+                },
+                Difference::Setting {
+                    field: "solcVersion".to_owned(),
+                    expected: "\"0.8.15\"".to_owned(),
+                    found: "\"0.8.14\"".to_owned(),
+                },
+                Difference::Source {
+                    path: "B.sol".to_owned(),
+                    expected: Some("bb".to_owned()),
+                    found: Some("b0".to_owned()),
+                },
+                Difference::Source {
+                    path: "C.sol".to_owned(),
+                    expected: None,
+                    found: Some("cc".to_owned()),
+                },
+            ]
+        );
+        assert!(Report::metadata_differences(&expected, &expected).is_empty());
+    }
+}
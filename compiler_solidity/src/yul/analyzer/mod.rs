@@ -0,0 +1,455 @@
+//!
+//! The Yul semantic analyzer.
+//!
+
+pub mod scope;
+
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::list::List as DiagnosticList;
+use crate::diagnostic::Diagnostic;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+use self::scope::Scope;
+
+///
+/// The Yul semantic analyzer.
+///
+/// Resolves the variable and function scopes, checks the number of arguments and return values,
+/// and the placement of `break`, `continue`, and `leave`, before the LLVM IR is generated.
+///
+#[derive(Debug, Default)]
+pub struct Analyzer {
+    /// The scope stack, where the last one is the innermost.
+    scopes: Vec<Scope>,
+    /// The number of enclosing `for` loop bodies within the current function.
+    loop_depth: usize,
+    /// The number of enclosing function definitions.
+    function_depth: usize,
+    /// The errors found so far, along with their locations.
+    errors: Vec<(Location, String)>,
+}
+
+impl Analyzer {
+    ///
+    /// Analyzes the object, reporting all errors at once.
+    ///
+    /// The errors are returned as a diagnostic list, where each diagnostic is given
+    /// the Solidity source code location, if the object has the `@src` annotations.
+    ///
+    pub fn analyze(object: &Object) -> anyhow::Result<()> {
+        let mut analyzer = Self::default();
+        analyzer.visit_object(object);
+
+        if !analyzer.errors.is_empty() {
+            let diagnostics = analyzer
+                .errors
+                .into_iter()
+                .map(|(location, message)| {
+                    Diagnostic::new_error(
+                        DiagnosticCode::YulAnalysis,
+                        format!("{} {}", location, message),
+                    )
+                    .with_source_location(
+                        location
+                            .solidity
+                            .map(|solidity| solidity.resolve(&object.source_paths)),
+                    )
+                })
+                .collect();
+            return Err(DiagnosticList::new(diagnostics).into());
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Declares a variable in the innermost scope.
+    ///
+    fn declare_variable(&mut self, identifier: &Identifier) {
+        if self.is_variable_visible(identifier.name.as_str()) {
+            self.errors.push((
+                identifier.location,
+                format!("Variable `{}` is already declared", identifier.name),
+            ));
+            return;
+        }
+
+        self.scopes
+            .last_mut()
+            .expect("Always exists")
+            .variables
+            .insert(identifier.name.clone());
+    }
+
+    ///
+    /// Checks whether the variable is visible from the innermost scope.
+    ///
+    /// The lookup stops at the function boundary, since the functions cannot access outer variables.
+    ///
+    fn is_variable_visible(&self, name: &str) -> bool {
+        for scope in self.scopes.iter().rev() {
+            if scope.variables.contains(name) {
+                return true;
+            }
+            if scope.is_function {
+                break;
+            }
+        }
+        false
+    }
+
+    ///
+    /// Checks that the variable is visible from the innermost scope.
+    ///
+    fn check_variable(&mut self, identifier: &Identifier) {
+        if !self.is_variable_visible(identifier.name.as_str()) {
+            self.errors.push((
+                identifier.location,
+                format!("Undeclared variable `{}`", identifier.name),
+            ));
+        }
+    }
+
+    ///
+    /// Returns the user-defined function input and output sizes, if the function is visible.
+    ///
+    fn function_signature(&self, name: &str) -> Option<(usize, usize)> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.functions.get(name).copied())
+    }
+
+    ///
+    /// Returns the number of values the expression evaluates to.
+    ///
+    /// Returns `None` if the function is undeclared, which is reported separately.
+    ///
+    fn output_size(&self, expression: &Expression) -> Option<usize> {
+        match expression {
+            Expression::FunctionCall(call) => match call.name {
+                Name::UserDefined(ref name) => self
+                    .function_signature(name.as_str())
+                    .map(|(_, output)| output),
+                ref name => name.output_size(),
+            },
+            Expression::Identifier(_) | Expression::Literal(_) => Some(1),
+        }
+    }
+
+    ///
+    /// Checks that the expression evaluates to the expected number of values.
+    ///
+    fn check_output_size(&mut self, expression: &Expression, expected: usize) {
+        if let Some(found) = self.output_size(expression) {
+            if found != expected {
+                self.errors.push((
+                    expression.location(),
+                    format!("Expected {} value(s), found {}", expected, found),
+                ));
+            }
+        }
+    }
+
+    ///
+    /// Visits the expression, which must evaluate to a single value.
+    ///
+    fn visit_value(&mut self, expression: &Expression) {
+        self.visit_expression(expression);
+        self.check_output_size(expression, 1);
+    }
+
+    ///
+    /// Declares the functions defined in the block, since they are visible in the whole block.
+    ///
+    fn hoist_functions(&mut self, block: &Block) {
+        for statement in block.statements.iter() {
+            if let Statement::FunctionDefinition(function) = statement {
+                if !matches!(Name::from(function.name.as_str()), Name::UserDefined(_))
+                    || self.function_signature(function.name.as_str()).is_some()
+                {
+                    self.errors.push((
+                        function.location,
+                        format!("Function `{}` is already declared", function.name),
+                    ));
+                    continue;
+                }
+
+                self.scopes
+                    .last_mut()
+                    .expect("Always exists")
+                    .functions
+                    .insert(
+                        function.name.clone(),
+                        (function.arguments.len(), function.result.len()),
+                    );
+            }
+        }
+    }
+
+    ///
+    /// Reports the statement which is not allowed in the current context.
+    ///
+    fn check_allowed(
+        &mut self,
+        is_allowed: bool,
+        location: Location,
+        statement: &str,
+        context: &str,
+    ) {
+        if !is_allowed {
+            self.errors.push((
+                location,
+                format!(
+                    "The `{}` statement is only allowed inside {}",
+                    statement, context
+                ),
+            ));
+        }
+    }
+}
+
+impl Visitor for Analyzer {
+    fn visit_block(&mut self, block: &Block) {
+        self.scopes.push(Scope::default());
+        self.hoist_functions(block);
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.scopes.pop();
+    }
+
+    fn visit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Expression(expression) => {
+                self.visit_expression(expression);
+                self.check_output_size(expression, 0);
+            }
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(ref expression) = declaration.expression {
+                    self.visit_expression(expression);
+                    self.check_output_size(expression, declaration.bindings.len());
+                }
+                for binding in declaration.bindings.iter() {
+                    self.declare_variable(binding);
+                }
+            }
+            Statement::Assignment(assignment) => {
+                for binding in assignment.bindings.iter() {
+                    self.check_variable(binding);
+                }
+                self.visit_expression(&assignment.initializer);
+                self.check_output_size(&assignment.initializer, assignment.bindings.len());
+            }
+            Statement::IfConditional(conditional) => {
+                self.visit_value(&conditional.condition);
+                self.visit_block(&conditional.block);
+            }
+            Statement::Continue(location) => self.check_allowed(
+                self.loop_depth > 0,
+                *location,
+                "continue",
+                "a `for` loop body",
+            ),
+            Statement::Break(location) => {
+                self.check_allowed(self.loop_depth > 0, *location, "break", "a `for` loop body")
+            }
+            Statement::Leave(location) => {
+                self.check_allowed(self.function_depth > 0, *location, "leave", "a function")
+            }
+            statement => crate::yul::visitor::walk_statement(self, statement),
+        }
+    }
+
+    fn visit_function_definition(&mut self, function: &FunctionDefinition) {
+        self.scopes.push(Scope::new_function());
+        for identifier in function.arguments.iter().chain(function.result.iter()) {
+            self.declare_variable(identifier);
+        }
+
+        let loop_depth = std::mem::take(&mut self.loop_depth);
+        self.function_depth += 1;
+        self.visit_block(&function.body);
+        self.function_depth -= 1;
+        self.loop_depth = loop_depth;
+
+        self.scopes.pop();
+    }
+
+    fn visit_switch(&mut self, switch: &Switch) {
+        self.visit_value(&switch.expression);
+        for case in switch.cases.iter() {
+            self.visit_block(&case.block);
+        }
+        if let Some(ref default) = switch.default {
+            self.visit_block(default);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop) {
+        self.scopes.push(Scope::default());
+        self.hoist_functions(&for_loop.initializer);
+        for statement in for_loop.initializer.statements.iter() {
+            self.visit_statement(statement);
+        }
+
+        self.visit_value(&for_loop.condition);
+
+        let loop_depth = std::mem::take(&mut self.loop_depth);
+        self.visit_block(&for_loop.finalizer);
+        self.loop_depth = loop_depth + 1;
+        self.visit_block(&for_loop.body);
+        self.loop_depth = loop_depth;
+
+        self.scopes.pop();
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::FunctionCall(call) => self.visit_function_call(call),
+            Expression::Identifier(identifier) => self.check_variable(identifier),
+            Expression::Literal(_) => {}
+        }
+    }
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        let input_size = match call.name {
+            Name::UserDefined(ref name) => match self.function_signature(name.as_str()) {
+                Some((input_size, _)) => Some(input_size),
+                None => {
+                    self.errors
+                        .push((call.location, format!("Undeclared function `{}`", name)));
+                    None
+                }
+            },
+            ref name => name.input_size(),
+        };
+        if let Some(input_size) = input_size {
+            if input_size != call.arguments.len() {
+                self.errors.push((
+                    call.location,
+                    format!(
+                        "Function `{}` expected {} arguments, found {}",
+                        call.name,
+                        input_size,
+                        call.arguments.len()
+                    ),
+                ));
+            }
+        }
+
+        for argument in call.arguments.iter() {
+            self.visit_value(argument);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::list::List as DiagnosticList;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::Analyzer;
+
+    fn analyze(code: &str) -> Vec<String> {
+        let mut lexer = Lexer::new(format!(r#"object "Test" {{ code {{ {} }} }}"#, code));
+        let object = Object::parse(&mut lexer, None).expect("Parsing error");
+        match Analyzer::analyze(&object) {
+            Ok(()) => vec![],
+            Err(error) => error
+                .downcast::<DiagnosticList>()
+                .expect("Always a diagnostic list")
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn ok() {
+        assert!(analyze(
+            r#"
+            function f(a, b) -> c { c := add(a, b) if c { leave } }
+            let x := f(1, 2)
+            for { let i := 0 } lt(i, x) { i := add(i, 1) } { if eq(i, 5) { break } continue }
+            sstore(0, x)
+            "#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn undeclared_variable() {
+        let errors = analyze("let x := add(y, 1) z := x");
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].ends_with("Undeclared variable `y`"));
+        assert!(errors[1].ends_with("Undeclared variable `z`"));
+    }
+
+    #[test]
+    fn undeclared_function() {
+        let errors = analyze("pop(f(1))");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].ends_with("Undeclared function `f`"));
+    }
+
+    #[test]
+    fn outer_variable_in_function() {
+        let errors = analyze("let x := 1 function f() -> r { r := x }");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].ends_with("Undeclared variable `x`"));
+    }
+
+    #[test]
+    fn wrong_argument_count() {
+        let errors = analyze("function f(a, b) {} f(1) sstore(0)");
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].ends_with("Function `f` expected 2 arguments, found 1"));
+        assert!(errors[1].ends_with("Function `sstore` expected 2 arguments, found 1"));
+    }
+
+    #[test]
+    fn wrong_value_count() {
+        let errors = analyze("function f() -> a, b {} let x := f() sstore(0, f())");
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].ends_with("Expected 1 value(s), found 2"));
+        assert!(errors[1].ends_with("Expected 1 value(s), found 2"));
+    }
+
+    #[test]
+    fn misplaced_control_flow() {
+        let errors = analyze("break continue leave");
+        assert_eq!(errors.len(), 3);
+        assert!(
+            errors[0].ends_with("The `break` statement is only allowed inside a `for` loop body")
+        );
+        assert!(errors[1]
+            .ends_with("The `continue` statement is only allowed inside a `for` loop body"));
+        assert!(errors[2].ends_with("The `leave` statement is only allowed inside a function"));
+    }
+
+    #[test]
+    fn redeclaration() {
+        let errors = analyze("let x := 1 let x := 2 function f() {} function f() {}");
+        assert_eq!(errors.len(), 2);
+        assert!(errors
+            .iter()
+            .any(|error| error.ends_with("Variable `x` is already declared")));
+        assert!(errors
+            .iter()
+            .any(|error| error.ends_with("Function `f` is already declared")));
+    }
+}
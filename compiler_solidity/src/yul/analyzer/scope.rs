@@ -0,0 +1,31 @@
+//!
+//! The Yul semantic analyzer scope.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+///
+/// The Yul semantic analyzer scope.
+///
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// The variables declared in the scope.
+    pub variables: HashSet<String>,
+    /// The functions declared in the scope, with their input and output sizes.
+    pub functions: HashMap<String, (usize, usize)>,
+    /// Whether the scope is a function boundary, which hides the outer variables.
+    pub is_function: bool,
+}
+
+impl Scope {
+    ///
+    /// A shortcut constructor for the function boundary scope.
+    ///
+    pub fn new_function() -> Self {
+        Self {
+            is_function: true,
+            ..Default::default()
+        }
+    }
+}
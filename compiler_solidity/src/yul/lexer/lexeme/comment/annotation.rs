@@ -0,0 +1,81 @@
+//!
+//! The `solc` comment annotation.
+//!
+
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+
+use crate::yul::lexer::token::location::solidity::Location as SolidityLocation;
+
+/// The `@src` annotation regular expression, which captures the source index, start and end.
+static SOURCE_REGEX: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new(r"@src\s+(-?\d+):(-?\d+):(-?\d+)").expect("Always valid"));
+
+/// The `@use-src` annotation entry regular expression, which captures the source index and path.
+static USE_SOURCE_ENTRY_REGEX: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new(r#"(\d+):"((?:[^"\\]|\\.)*)""#).expect("Always valid"));
+
+///
+/// The `solc` comment annotation.
+///
+/// The Yul emitted by `solc` is interleaved with comments like `/// @use-src 0:"A.sol"` and
+/// `/// @src 0:123:456  "snippet"`, which map the Yul code back to the Solidity source code.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Annotation {
+    /// The `@use-src` annotation, which maps the source file indexes to their paths.
+    UseSource(BTreeMap<usize, String>),
+    /// The `@src` annotation, which is `None` for the `-1:-1:-1` unknown location.
+    Source(Option<SolidityLocation>),
+}
+
+impl Annotation {
+    /// The source file mapping annotation tag.
+    pub const USE_SOURCE: &'static str = "@use-src";
+
+    ///
+    /// Parses the annotation from the comment text.
+    ///
+    /// Only the first annotation is taken, since the rest of the comment is usually a code snippet.
+    ///
+    pub fn parse(text: &str) -> Option<Self> {
+        if !text.contains('@') {
+            return None;
+        }
+
+        let source = SOURCE_REGEX.captures(text);
+        let use_source_position = text.find(Self::USE_SOURCE);
+
+        match (source, use_source_position) {
+            (Some(captures), position)
+                if position.map_or(true, |position| {
+                    captures.get(0).expect("Always exists").start() < position
+                }) =>
+            {
+                let index = captures[1].parse::<usize>();
+                let start = captures[2].parse::<usize>();
+                let end = captures[3].parse::<usize>();
+                let location = match (index, start, end) {
+                    (Ok(index), Ok(start), Ok(end)) => {
+                        Some(SolidityLocation::new(index, start, end))
+                    }
+                    _ => None,
+                };
+                Some(Self::Source(location))
+            }
+            (_, Some(position)) => {
+                let mapping = USE_SOURCE_ENTRY_REGEX
+                    .captures_iter(&text[position + Self::USE_SOURCE.len()..])
+                    .filter_map(|captures| {
+                        let index = captures[1].parse::<usize>().ok()?;
+                        let path = captures[2].replace("\\\"", "\"").replace("\\\\", "\\");
+                        Some((index, path))
+                    })
+                    .collect();
+                Some(Self::UseSource(mapping))
+            }
+            _ => None,
+        }
+    }
+}
@@ -2,9 +2,11 @@
 //! The comment lexeme.
 //!
 
+pub mod annotation;
 pub mod multi_line;
 pub mod single_line;
 
+use self::annotation::Annotation;
 use self::multi_line::Comment as MultiLineComment;
 use self::single_line::Comment as SingleLineComment;
 
@@ -12,7 +14,6 @@ use self::single_line::Comment as SingleLineComment;
 /// The comment lexeme.
 ///
 #[derive(Debug, Clone, PartialEq)]
-#[allow(dead_code)]
 pub enum Comment {
     /// The single-line comment.
     SingleLine(SingleLineComment),
@@ -22,21 +23,33 @@ pub enum Comment {
 
 impl Comment {
     ///
-    /// Returns the comment's length, including the trimmed whitespace around it.
+    /// Returns the comment's length, including the trimmed whitespace around it, and the comment itself.
     ///
-    pub fn parse(input: &str) -> Option<usize> {
+    pub fn parse(input: &str) -> Option<(usize, Self)> {
         let mut length = 0;
         let trimmed_start = input.trim_start();
         length += input.len() - trimmed_start.len();
 
         if trimmed_start.starts_with(SingleLineComment::START) {
-            return Some(length + SingleLineComment::parse(trimmed_start));
+            let (comment_length, comment) = SingleLineComment::parse(trimmed_start);
+            return Some((length + comment_length, Self::SingleLine(comment)));
         }
 
         if trimmed_start.starts_with(MultiLineComment::START) {
-            return Some(length + MultiLineComment::parse(trimmed_start));
+            let (comment_length, comment) = MultiLineComment::parse(trimmed_start);
+            return Some((length + comment_length, Self::MultiLine(comment)));
         }
 
         None
     }
+
+    ///
+    /// Returns the `solc` annotation, if the comment contains one.
+    ///
+    pub fn annotation(&self) -> Option<Annotation> {
+        match self {
+            Self::SingleLine(inner) => Annotation::parse(inner.text.as_str()),
+            Self::MultiLine(inner) => Annotation::parse(inner.text.as_str()),
+        }
+    }
 }
@@ -6,7 +6,10 @@
 /// The multi-line comment lexeme.
 ///
 #[derive(Debug, Clone, PartialEq)]
-pub struct Comment {}
+pub struct Comment {
+    /// The comment text without the delimiters.
+    pub text: String,
+}
 
 impl Comment {
     /// The start symbol.
@@ -15,10 +18,11 @@ impl Comment {
     pub const END: &'static str = "*/";
 
     ///
-    /// Returns the comment's length, including the trimmed whitespace around it.
+    /// Returns the comment's length and the comment itself.
     ///
-    pub fn parse(input: &str) -> usize {
+    pub fn parse(input: &str) -> (usize, Self) {
         let end_position = input.find(Self::END).unwrap_or(input.len());
-        end_position + Self::END.len()
+        let text = input[Self::START.len()..end_position].to_owned();
+        (end_position + Self::END.len(), Self { text })
     }
 }
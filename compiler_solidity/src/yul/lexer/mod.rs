@@ -3,7 +3,11 @@
 //!
 
 pub mod lexeme;
+pub mod token;
 
+use std::collections::BTreeMap;
+
+use self::lexeme::comment::annotation::Annotation;
 use self::lexeme::comment::Comment;
 use self::lexeme::keyword::Keyword;
 use self::lexeme::literal::boolean::Boolean as BooleanLiteral;
@@ -12,6 +16,8 @@ use self::lexeme::literal::string::String as StringLiteral;
 use self::lexeme::literal::Literal;
 use self::lexeme::symbol::Symbol;
 use self::lexeme::Lexeme;
+use self::token::location::Location;
+use self::token::Token;
 
 ///
 /// The compiler lexer.
@@ -23,8 +29,12 @@ pub struct Lexer {
     regexp: regex::Regex,
     /// The position in the source code.
     index: usize,
-    /// The peeked lexeme, waiting to be fetched.
-    peeked: Option<Lexeme>,
+    /// The line and column of the current position.
+    location: Location,
+    /// The peeked token, waiting to be fetched.
+    peeked: Option<Token>,
+    /// The Solidity source file paths, taken from the `@use-src` annotations.
+    source_paths: BTreeMap<usize, String>,
 }
 
 impl Lexer {
@@ -38,37 +48,46 @@ impl Lexer {
             input,
             regexp: Symbol::regexp(),
             index: 0,
+            location: Location::default(),
             peeked: None,
+            source_paths: BTreeMap::new(),
         }
     }
 
     ///
-    /// Advances the lexer, returning the next lexeme.
+    /// Advances the lexer, returning the next token.
     ///
     #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> anyhow::Result<Lexeme> {
+    pub fn next(&mut self) -> anyhow::Result<Token> {
         if let Some(peeked) = self.peeked.take() {
             return Ok(peeked);
         }
 
         loop {
-            if let Some(length) = Comment::parse(&self.input[self.index..]) {
-                self.index += length;
+            if let Some((length, comment)) = Comment::parse(&self.input[self.index..]) {
+                match comment.annotation() {
+                    Some(Annotation::UseSource(paths)) => self.source_paths.extend(paths),
+                    Some(Annotation::Source(location)) => self.location.solidity = location,
+                    None => {}
+                }
+                self.advance(length);
                 continue;
             }
 
+            let location = self.location;
+
             if let Some((length, literal)) = StringLiteral::parse(&self.input[self.index..]) {
-                self.index += length;
+                self.advance(length);
                 let lexeme = Lexeme::Literal(Literal::String(literal));
-                return Ok(lexeme);
+                return Ok(Token::new(location, lexeme, length));
             }
 
             let r#match = match self.regexp.find(&self.input[self.index..]) {
                 Some(r#match) => r#match,
-                None => return Ok(Lexeme::EndOfFile),
+                None => return Ok(Token::new(location, Lexeme::EndOfFile, 0)),
             };
 
-            let lexeme = if r#match.start() != 0 {
+            let (lexeme, length) = if r#match.start() != 0 {
                 let lexeme = match Keyword::try_from(
                     &self.input[self.index..self.index + r#match.start()],
                 ) {
@@ -82,34 +101,34 @@ impl Lexer {
                         } else if Lexeme::is_identifier(string.as_str()) {
                             Lexeme::Identifier(string)
                         } else {
-                            anyhow::bail!("Invalid lexeme `{}`", string);
+                            anyhow::bail!("{} Invalid lexeme `{}`", location, string);
                         }
                     }
                 };
-                self.index += r#match.start();
-                lexeme
+                (lexeme, r#match.start())
             } else if !r#match.as_str().trim().is_empty() {
                 let lexeme = match Symbol::try_from(r#match.as_str()) {
                     Ok(symbol) => Lexeme::Symbol(symbol),
                     Err(string) => {
-                        anyhow::bail!("Invalid lexeme `{}`", string);
+                        anyhow::bail!("{} Invalid lexeme `{}`", location, string);
                     }
                 };
-                self.index += r#match.as_str().len();
-                lexeme
+                (lexeme, r#match.as_str().len())
             } else {
-                self.index += r#match.as_str().len();
+                let length = r#match.as_str().len();
+                self.advance(length);
                 continue;
             };
 
-            return Ok(lexeme);
+            self.advance(length);
+            return Ok(Token::new(location, lexeme, length));
         }
     }
 
     ///
-    /// Peeks the next lexeme without advancing the iterator.
+    /// Peeks the next token without advancing the iterator.
     ///
-    pub fn peek(&mut self) -> anyhow::Result<Lexeme> {
+    pub fn peek(&mut self) -> anyhow::Result<Token> {
         match self.peeked {
             Some(ref peeked) => Ok(peeked.clone()),
             None => {
@@ -119,4 +138,20 @@ impl Lexer {
             }
         }
     }
+
+    ///
+    /// Returns the Solidity source file paths, collected so far.
+    ///
+    pub fn source_paths(&self) -> &BTreeMap<usize, String> {
+        &self.source_paths
+    }
+
+    ///
+    /// Advances the position by `length` bytes, updating the line and column.
+    ///
+    fn advance(&mut self, length: usize) {
+        self.location
+            .advance(&self.input[self.index..self.index + length]);
+        self.index += length;
+    }
 }
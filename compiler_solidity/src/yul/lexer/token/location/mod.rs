@@ -0,0 +1,61 @@
+//!
+//! The lexical token location.
+//!
+
+pub mod solidity;
+
+use self::solidity::Location as SolidityLocation;
+
+///
+/// The lexical token location.
+///
+/// Both the line and column are 1-based, as they are shown to the user.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    /// The line number.
+    pub line: usize,
+    /// The column number.
+    pub column: usize,
+    /// The Solidity source code location, if annotated by `solc`.
+    pub solidity: Option<SolidityLocation>,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(line: usize, column: usize) -> Self {
+        Self {
+            line,
+            column,
+            solidity: None,
+        }
+    }
+
+    ///
+    /// Advances the location over the consumed source code slice.
+    ///
+    pub fn advance(&mut self, consumed: &str) {
+        for character in consumed.chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
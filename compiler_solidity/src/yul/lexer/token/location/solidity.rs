@@ -0,0 +1,50 @@
+//!
+//! The Solidity source code location.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The Solidity source code location.
+///
+/// Taken from the `@src` annotations, which `solc` leaves in its Yul output.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    /// The source file index, resolved with the `@use-src` annotation.
+    pub index: usize,
+    /// The start byte offset.
+    pub start: usize,
+    /// The end byte offset.
+    pub end: usize,
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(index: usize, start: usize, end: usize) -> Self {
+        Self { index, start, end }
+    }
+
+    ///
+    /// Converts the location into the `solc` standard JSON one, resolving the source file path.
+    ///
+    /// If the path is not found, the source file index is used instead.
+    ///
+    pub fn resolve(&self, source_paths: &BTreeMap<usize, String>) -> SourceLocation {
+        let file = source_paths
+            .get(&self.index)
+            .cloned()
+            .unwrap_or_else(|| self.index.to_string());
+        SourceLocation::new(file, self.start as isize, self.end as isize)
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.index, self.start, self.end)
+    }
+}
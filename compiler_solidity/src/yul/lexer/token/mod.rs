@@ -0,0 +1,43 @@
+//!
+//! The lexical token.
+//!
+
+pub mod location;
+
+use crate::yul::lexer::lexeme::Lexeme;
+
+use self::location::Location;
+
+///
+/// The lexical token.
+///
+/// Wraps a lexeme, adding its span in the source code.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    /// The token location.
+    pub location: Location,
+    /// The lexeme.
+    pub lexeme: Lexeme,
+    /// The token length in bytes of the source text.
+    pub length: usize,
+}
+
+impl Token {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, lexeme: Lexeme, length: usize) -> Self {
+        Self {
+            location,
+            lexeme,
+            length,
+        }
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lexeme)
+    }
+}
@@ -2,5 +2,7 @@
 //! The Yul IR compiling tools.
 //!
 
+pub mod analyzer;
 pub mod lexer;
 pub mod parser;
+pub mod visitor;
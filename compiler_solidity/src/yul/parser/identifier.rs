@@ -4,6 +4,8 @@
 
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::r#type::Type;
 
@@ -12,6 +14,8 @@ use crate::yul::parser::r#type::Type;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Identifier {
+    /// The location.
+    pub location: Location,
     /// The identifier string.
     pub name: String,
     /// The type, if it has been explicitly specified.
@@ -19,28 +23,57 @@ pub struct Identifier {
 }
 
 impl Identifier {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, name: String) -> Self {
+        Self {
+            location,
+            name,
+            yul_type: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for a typed identifier.
+    ///
+    pub fn new_with_type(location: Location, name: String, yul_type: Option<Type>) -> Self {
+        Self {
+            location,
+            name,
+            yul_type,
+        }
+    }
+
     ///
     /// Parses the identifier list where the types cannot be specified.
     ///
     pub fn parse_list(
         lexer: &mut Lexer,
-        mut initial: Option<Lexeme>,
-    ) -> anyhow::Result<(Vec<String>, Option<Lexeme>)> {
+        mut initial: Option<Token>,
+    ) -> anyhow::Result<(Vec<Self>, Option<Token>)> {
         let mut result = Vec::new();
 
         let mut expected_comma = false;
         loop {
-            let lexeme = crate::yul::parser::take_or_next(initial.take(), lexer)?;
+            let token = crate::yul::parser::take_or_next(initial.take(), lexer)?;
 
-            match lexeme {
-                Lexeme::Identifier(identifier) if !expected_comma => {
-                    result.push(identifier);
+            match token {
+                Token {
+                    location,
+                    lexeme: Lexeme::Identifier(identifier),
+                    ..
+                } if !expected_comma => {
+                    result.push(Self::new(location, identifier));
                     expected_comma = true;
                 }
-                Lexeme::Symbol(Symbol::Comma) if expected_comma => {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } if expected_comma => {
                     expected_comma = false;
                 }
-                lexeme => return Ok((result, Some(lexeme))),
+                token => return Ok((result, Some(token))),
             }
         }
     }
@@ -50,34 +83,51 @@ impl Identifier {
     ///
     pub fn parse_typed_list(
         lexer: &mut Lexer,
-        mut initial: Option<Lexeme>,
-    ) -> anyhow::Result<(Vec<Self>, Option<Lexeme>)> {
+        mut initial: Option<Token>,
+    ) -> anyhow::Result<(Vec<Self>, Option<Token>)> {
         let mut result = Vec::new();
 
         let mut expected_comma = false;
         loop {
-            let lexeme = crate::yul::parser::take_or_next(initial.take(), lexer)?;
+            let token = crate::yul::parser::take_or_next(initial.take(), lexer)?;
 
-            match lexeme {
-                Lexeme::Identifier(identifier) if !expected_comma => {
+            match token {
+                Token {
+                    location,
+                    lexeme: Lexeme::Identifier(identifier),
+                    ..
+                } if !expected_comma => {
                     let yul_type = match lexer.peek()? {
-                        Lexeme::Symbol(Symbol::Colon) => {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
                             lexer.next()?;
                             Some(Type::parse(lexer, None)?)
                         }
                         _ => None,
                     };
-                    result.push(Self {
-                        name: identifier,
-                        yul_type,
-                    });
+                    result.push(Self::new_with_type(location, identifier, yul_type));
                     expected_comma = true;
                 }
-                Lexeme::Symbol(Symbol::Comma) if expected_comma => {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } if expected_comma => {
                     expected_comma = false;
                 }
-                lexeme => return Ok((result, Some(lexeme))),
+                token => return Ok((result, Some(token))),
             }
         }
     }
 }
+
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(ref yul_type) = self.yul_type {
+            write!(f, ":{}", yul_type)?;
+        }
+        Ok(())
+    }
+}
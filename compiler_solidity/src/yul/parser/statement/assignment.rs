@@ -4,6 +4,8 @@
 
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::identifier::Identifier;
 use crate::yul::parser::statement::expression::Expression;
@@ -13,8 +15,10 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Assignment {
+    /// The location.
+    pub location: Location,
     /// The variable bindings.
-    pub bindings: Vec<String>,
+    pub bindings: Vec<Identifier>,
     /// The initializing expression.
     pub initializer: Expression,
 }
@@ -23,42 +27,78 @@ impl Assignment {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let identifier = match lexeme {
-            Lexeme::Identifier(identifier) => identifier,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{identifier}"], lexeme);
+        let (location, identifier) = match token {
+            Token {
+                location,
+                lexeme: Lexeme::Identifier(identifier),
+                ..
+            } => (location, identifier),
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["{identifier}"],
+                    token.lexeme
+                );
             }
         };
 
         match lexer.peek()? {
-            Lexeme::Symbol(Symbol::Assignment) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Assignment),
+                ..
+            } => {
                 lexer.next()?;
 
                 Ok(Self {
-                    bindings: vec![identifier],
+                    location,
+                    bindings: vec![Identifier::new(location, identifier)],
                     initializer: Expression::parse(lexer, None)?,
                 })
             }
-            Lexeme::Symbol(Symbol::Comma) => {
-                let (identifiers, next) =
-                    Identifier::parse_list(lexer, Some(Lexeme::Identifier(identifier)))?;
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Comma),
+                ..
+            } => {
+                let (identifiers, next) = Identifier::parse_list(
+                    lexer,
+                    Some(Token::new(
+                        location,
+                        Lexeme::Identifier(identifier.clone()),
+                        identifier.len(),
+                    )),
+                )?;
 
                 match crate::yul::parser::take_or_next(next, lexer)? {
-                    Lexeme::Symbol(Symbol::Assignment) => {}
-                    lexeme => {
-                        anyhow::bail!("Expected one of {:?}, found `{}`", [":="], lexeme);
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Assignment),
+                        ..
+                    } => {}
+                    token => {
+                        anyhow::bail!(
+                            "{} Expected one of {:?}, found `{}`",
+                            token.location,
+                            [":="],
+                            token.lexeme
+                        );
                     }
                 }
 
                 Ok(Self {
+                    location,
                     bindings: identifiers,
                     initializer: Expression::parse(lexer, None)?,
                 })
             }
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", [":=", ","], lexeme),
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                [":=", ","],
+                token.lexeme
+            ),
         }
     }
 }
@@ -74,8 +114,20 @@ where
         };
 
         if self.bindings.len() == 1 {
-            let name = self.bindings.remove(0);
-            context.build_store(context.function().stack[name.as_str()], value.to_llvm());
+            let identifier = self.bindings.remove(0);
+            let pointer = context
+                .function()
+                .stack
+                .get(identifier.name.as_str())
+                .copied()
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{} Assignment to an undeclared variable `{}`",
+                        identifier.location,
+                        identifier.name
+                    )
+                })?;
+            context.build_store(pointer, value.to_llvm());
             return Ok(());
         }
 
@@ -102,9 +154,36 @@ where
                 format!("assignment_binding_{}_value", index).as_str(),
             );
 
-            context.build_store(context.function().stack[binding.as_str()], value);
+            let pointer = context
+                .function()
+                .stack
+                .get(binding.name.as_str())
+                .copied()
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{} Assignment to an undeclared variable `{}`",
+                        binding.location,
+                        binding.name
+                    )
+                })?;
+            context.build_store(pointer, value);
         }
 
         Ok(())
     }
 }
+
+impl std::fmt::Display for Assignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} := {}",
+            self.bindings
+                .iter()
+                .map(|binding| binding.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.initializer
+        )
+    }
+}
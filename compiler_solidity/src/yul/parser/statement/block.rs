@@ -4,6 +4,9 @@
 
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::solidity::Location as SolidityLocation;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::assignment::Assignment;
 use crate::yul::parser::statement::expression::Expression;
@@ -14,6 +17,8 @@ use crate::yul::parser::statement::Statement;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Block {
+    /// The location.
+    pub location: Location,
     /// The block statements.
     pub statements: Vec<Statement>,
 }
@@ -22,61 +27,112 @@ impl Block {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let mut statements = Vec::new();
 
-        match lexeme {
-            Lexeme::Symbol(Symbol::BracketCurlyLeft) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["{"], lexeme),
-        }
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["{"],
+                token.lexeme
+            ),
+        };
 
         let mut remaining = None;
 
         loop {
             match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
-                lexeme @ Lexeme::Keyword(_) => {
-                    let (statement, next) = Statement::parse(lexer, Some(lexeme))?;
+                token @ Token {
+                    lexeme: Lexeme::Keyword(_),
+                    ..
+                } => {
+                    let (statement, next) = Statement::parse(lexer, Some(token))?;
                     remaining = next;
                     statements.push(statement);
                 }
-                lexeme @ Lexeme::Literal(_) => {
+                token @ Token {
+                    lexeme: Lexeme::Literal(_),
+                    ..
+                } => {
                     statements
-                        .push(Expression::parse(lexer, Some(lexeme)).map(Statement::Expression)?);
+                        .push(Expression::parse(lexer, Some(token)).map(Statement::Expression)?);
                 }
-                lexeme @ Lexeme::Identifier(_) => match lexer.peek()? {
-                    Lexeme::Symbol(Symbol::Assignment) => {
+                token @ Token {
+                    lexeme: Lexeme::Identifier(_),
+                    ..
+                } => match lexer.peek()? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Assignment),
+                        ..
+                    } => {
                         statements.push(
-                            Assignment::parse(lexer, Some(lexeme)).map(Statement::Assignment)?,
+                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
                         );
                     }
-                    Lexeme::Symbol(Symbol::Comma) => {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Comma),
+                        ..
+                    } => {
                         statements.push(
-                            Assignment::parse(lexer, Some(lexeme)).map(Statement::Assignment)?,
+                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
                         );
                     }
                     _ => {
                         statements.push(
-                            Expression::parse(lexer, Some(lexeme)).map(Statement::Expression)?,
+                            Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
                         );
                     }
                 },
-                lexeme @ Lexeme::Symbol(Symbol::BracketCurlyLeft) => {
-                    statements.push(Block::parse(lexer, Some(lexeme)).map(Statement::Block)?)
-                }
-                Lexeme::Symbol(Symbol::BracketCurlyRight) => break,
-                lexeme => {
+                token @ Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                    ..
+                } => statements.push(Block::parse(lexer, Some(token)).map(Statement::Block)?),
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } => break,
+                token => {
                     anyhow::bail!(
-                        "Expected one of {:?}, found `{}`",
+                        "{} Expected one of {:?}, found `{}`",
+                        token.location,
                         ["{keyword}", "{expression}", "{identifier}", "{", "}"],
-                        lexeme
+                        token.lexeme
                     );
                 }
             }
         }
 
-        Ok(Self { statements })
+        Ok(Self {
+            location,
+            statements,
+        })
+    }
+}
+
+impl Block {
+    ///
+    /// Attaches the Solidity source code location to the code generation error.
+    ///
+    /// The innermost statement location is kept, since it is the most precise one.
+    ///
+    fn annotate_error(
+        error: anyhow::Error,
+        solidity_location: Option<SolidityLocation>,
+    ) -> anyhow::Error {
+        match solidity_location {
+            Some(solidity_location) if error.downcast_ref::<SolidityLocation>().is_none() => {
+                error.context(solidity_location)
+            }
+            _ => error,
+        }
     }
 }
 
@@ -102,7 +158,10 @@ where
         }
 
         for function in functions.into_iter() {
-            function.into_llvm(context)?;
+            let solidity_location = function.location.solidity;
+            function
+                .into_llvm(context)
+                .map_err(|error| Self::annotate_error(error, solidity_location))?;
         }
 
         context.set_function(current_function.clone());
@@ -112,34 +171,52 @@ where
                 break;
             }
 
-            match statement {
-                Statement::Block(block) => {
-                    block.into_llvm(context)?;
-                }
-                Statement::Expression(expression) => {
-                    expression.into_llvm(context)?;
-                }
-                Statement::VariableDeclaration(statement) => statement.into_llvm(context)?,
-                Statement::Assignment(statement) => statement.into_llvm(context)?,
-                Statement::IfConditional(statement) => statement.into_llvm(context)?,
-                Statement::Switch(statement) => statement.into_llvm(context)?,
-                Statement::ForLoop(statement) => statement.into_llvm(context)?,
-                Statement::Continue => {
+            let solidity_location = statement.location().solidity;
+            let result = match statement {
+                Statement::Block(block) => block.into_llvm(context),
+                Statement::Expression(expression) => expression.into_llvm(context).map(|_| ()),
+                Statement::VariableDeclaration(statement) => statement.into_llvm(context),
+                Statement::Assignment(statement) => statement.into_llvm(context),
+                Statement::IfConditional(statement) => statement.into_llvm(context),
+                Statement::Switch(statement) => statement.into_llvm(context),
+                Statement::ForLoop(statement) => statement.into_llvm(context),
+                Statement::Continue(_) => {
                     context.build_unconditional_branch(context.r#loop().continue_block);
                     break;
                 }
-                Statement::Break => {
+                Statement::Break(_) => {
                     context.build_unconditional_branch(context.r#loop().join_block);
                     break;
                 }
-                Statement::Leave => {
+                Statement::Leave(_) => {
                     context.build_unconditional_branch(context.function().return_block);
                     break;
                 }
-                statement => anyhow::bail!("Unexpected local statement: {:?}", statement),
-            }
+                statement => Err(anyhow::anyhow!(
+                    "{} Unexpected local statement: {:?}",
+                    statement.location(),
+                    statement
+                )),
+            };
+            result.map_err(|error| Self::annotate_error(error, solidity_location))?;
         }
 
         Ok(())
     }
 }
+
+impl std::fmt::Display for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.statements.is_empty() {
+            return write!(f, "{{ }}");
+        }
+
+        writeln!(f, "{{")?;
+        for statement in self.statements.iter() {
+            for line in statement.to_string().lines() {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
@@ -4,6 +4,8 @@
 
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::block::Block;
 
@@ -12,6 +14,8 @@ use crate::yul::parser::statement::block::Block;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Code {
+    /// The location.
+    pub location: Location,
     /// The main block.
     pub block: Block,
 }
@@ -20,17 +24,26 @@ impl Code {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        match lexeme {
-            Lexeme::Keyword(Keyword::Code) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["code"], lexeme),
-        }
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Code),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["code"],
+                token.lexeme
+            ),
+        };
 
         let block = Block::parse(lexer, None)?;
 
-        Ok(Self { block })
+        Ok(Self { location, block })
     }
 }
 
@@ -44,3 +57,9 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "code {}", self.block)
+    }
+}
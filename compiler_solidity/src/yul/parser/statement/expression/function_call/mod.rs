@@ -9,6 +9,8 @@ use inkwell::values::BasicValue;
 
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::expression::Expression;
 
@@ -19,6 +21,8 @@ use self::name::Name;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct FunctionCall {
+    /// The location.
+    pub location: Location,
     /// The function name.
     pub name: Name,
     /// The function arguments expression list.
@@ -29,31 +33,49 @@ impl FunctionCall {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
-
-        let name = match lexeme {
-            Lexeme::Identifier(identifier) => Name::from(identifier.as_str()),
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{identifier}"], lexeme);
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let (location, name) = match token {
+            Token {
+                location,
+                lexeme: Lexeme::Identifier(identifier),
+                ..
+            } => (location, Name::from(identifier.as_str())),
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["{identifier}"],
+                    token.lexeme
+                );
             }
         };
 
         let mut arguments = Vec::new();
         loop {
             let argument = match lexer.next()? {
-                Lexeme::Symbol(Symbol::ParenthesisRight) => break,
-                lexeme => Expression::parse(lexer, Some(lexeme))?,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                token => Expression::parse(lexer, Some(token))?,
             };
 
             arguments.push(argument);
 
             match lexer.peek()? {
-                Lexeme::Symbol(Symbol::Comma) => {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => {
                     lexer.next()?;
                     continue;
                 }
-                Lexeme::Symbol(Symbol::ParenthesisRight) => {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => {
                     lexer.next()?;
                     break;
                 }
@@ -61,7 +83,11 @@ impl FunctionCall {
             }
         }
 
-        Ok(Self { name, arguments })
+        Ok(Self {
+            location,
+            name,
+            arguments,
+        })
     }
 
     ///
@@ -74,20 +100,33 @@ impl FunctionCall {
     where
         D: compiler_llvm_context::Dependency,
     {
+        let location = self.location;
+
         match self.name {
             Name::UserDefined(name)
                 if name.contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX) =>
             {
                 let mut values = Vec::with_capacity(self.arguments.len());
                 for argument in self.arguments.into_iter() {
-                    let value = argument.into_llvm(context)?.expect("Always exists").value;
+                    let value = argument
+                        .into_llvm(context)?
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "{} Function `{}` argument has no value",
+                                location,
+                                name
+                            )
+                        })?
+                        .value;
                     values.push(value);
                 }
                 let function = context
                     .functions
                     .get(name.as_str())
                     .cloned()
-                    .unwrap_or_else(|| panic!("Undeclared function {}", name));
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("{} Undeclared function `{}`", location, name)
+                    })?;
 
                 if let Some(compiler_llvm_context::FunctionReturn::Compound { size, .. }) =
                     function.r#return
@@ -117,7 +156,8 @@ impl FunctionCall {
 
                 if function.value.count_params() as usize != (values.len() - 2) {
                     anyhow::bail!(
-                        "Function `{}` expected {} arguments, found {}",
+                        "{} Function `{}` expected {} arguments, found {}",
+                        location,
                         name,
                         function.value.count_params(),
                         values.len()
@@ -133,7 +173,15 @@ impl FunctionCall {
                 if let Some(compiler_llvm_context::FunctionReturn::Compound { .. }) =
                     function.r#return
                 {
-                    let return_pointer = return_value.expect("Always exists").into_pointer_value();
+                    let return_pointer = return_value
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "{} Function `{}` return value is missing",
+                                location,
+                                name
+                            )
+                        })?
+                        .into_pointer_value();
                     let return_value = context.build_load(
                         return_pointer,
                         format!("{}_near_call_return_value", name).as_str(),
@@ -146,14 +194,25 @@ impl FunctionCall {
             Name::UserDefined(name) => {
                 let mut values = Vec::with_capacity(self.arguments.len());
                 for argument in self.arguments.into_iter() {
-                    let value = argument.into_llvm(context)?.expect("Always exists").value;
+                    let value = argument
+                        .into_llvm(context)?
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "{} Function `{}` argument has no value",
+                                location,
+                                name
+                            )
+                        })?
+                        .value;
                     values.push(value);
                 }
                 let function = context
                     .functions
                     .get(name.as_str())
                     .cloned()
-                    .unwrap_or_else(|| panic!("Undeclared function {}", name));
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("{} Undeclared function `{}`", location, name)
+                    })?;
 
                 if let Some(compiler_llvm_context::FunctionReturn::Compound { size, .. }) =
                     function.r#return
@@ -169,7 +228,8 @@ impl FunctionCall {
 
                 if function.value.count_params() as usize != values.len() {
                     anyhow::bail!(
-                        "Function `{}` expected {} arguments, found {}",
+                        "{} Function `{}` expected {} arguments, found {}",
+                        location,
                         name,
                         function.value.count_params(),
                         values.len()
@@ -185,7 +245,15 @@ impl FunctionCall {
                 if let Some(compiler_llvm_context::FunctionReturn::Compound { .. }) =
                     function.r#return
                 {
-                    let return_pointer = return_value.expect("Always exists").into_pointer_value();
+                    let return_pointer = return_value
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "{} Function `{}` return value is missing",
+                                location,
+                                name
+                            )
+                        })?
+                        .into_pointer_value();
                     let return_value = context
                         .build_load(return_pointer, format!("{}_return_value", name).as_str());
                     Ok(Some(return_value))
@@ -364,10 +432,9 @@ impl FunctionCall {
             }
             Name::LoadImmutable => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
-                let key = arguments[0]
-                    .original
-                    .take()
-                    .ok_or_else(|| anyhow::anyhow!("`load_immutable` literal is missing"))?;
+                let key = arguments[0].original.take().ok_or_else(|| {
+                    anyhow::anyhow!("{} `load_immutable` literal is missing", location)
+                })?;
 
                 if key.as_str() == "library_deploy_address" {
                     return Ok(context.build_call(
@@ -380,7 +447,12 @@ impl FunctionCall {
                 }
 
                 let key_numeric: u64 = key.parse().map_err(|error| {
-                    anyhow::anyhow!("Found a non-numeric immutable index {}: {}", key, error)
+                    anyhow::anyhow!(
+                        "{} Found a non-numeric immutable index {}: {}",
+                        location,
+                        key,
+                        error
+                    )
                 })?;
                 let key_normalized = key_numeric * (compiler_common::SIZE_FIELD as u64);
                 let index = context.field_const(key_normalized);
@@ -388,17 +460,21 @@ impl FunctionCall {
             }
             Name::SetImmutable => {
                 let mut arguments = self.pop_arguments::<D, 3>(context)?;
-                let key = arguments[1]
-                    .original
-                    .take()
-                    .ok_or_else(|| anyhow::anyhow!("`load_immutable` literal is missing"))?;
+                let key = arguments[1].original.take().ok_or_else(|| {
+                    anyhow::anyhow!("{} `load_immutable` literal is missing", location)
+                })?;
 
                 if key.as_str() == "library_deploy_address" {
                     return Ok(None);
                 }
 
                 let key_numeric: u64 = key.parse().map_err(|error| {
-                    anyhow::anyhow!("Found a non-numeric immutable index {}: {}", key, error)
+                    anyhow::anyhow!(
+                        "{} Found a non-numeric immutable index {}: {}",
+                        location,
+                        key,
+                        error
+                    )
                 })?;
                 let key_normalized = key_numeric * (compiler_common::SIZE_FIELD as u64);
                 context
@@ -605,18 +681,16 @@ impl FunctionCall {
             }
             Name::DataOffset => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
-                let identifier = arguments[0]
-                    .original
-                    .take()
-                    .ok_or_else(|| anyhow::anyhow!("`dataoffset` object identifier is missing"))?;
+                let identifier = arguments[0].original.take().ok_or_else(|| {
+                    anyhow::anyhow!("{} `dataoffset` object identifier is missing", location)
+                })?;
                 compiler_llvm_context::create::contract_hash(context, identifier)
             }
             Name::DataSize => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
-                let identifier = arguments[0]
-                    .original
-                    .take()
-                    .ok_or_else(|| anyhow::anyhow!("`dataoffset` object identifier is missing"))?;
+                let identifier = arguments[0].original.take().ok_or_else(|| {
+                    anyhow::anyhow!("{} `dataoffset` object identifier is missing", location)
+                })?;
                 compiler_llvm_context::create::header_size(context, identifier)
             }
             Name::DataCopy => {
@@ -634,10 +708,9 @@ impl FunctionCall {
 
             Name::LinkerSymbol => {
                 let mut arguments = self.pop_arguments::<D, 1>(context)?;
-                let path = arguments[0]
-                    .original
-                    .take()
-                    .ok_or_else(|| anyhow::anyhow!("Linker symbol literal is missing"))?;
+                let path = arguments[0].original.take().ok_or_else(|| {
+                    anyhow::anyhow!("{} Linker symbol literal is missing", location)
+                })?;
 
                 Ok(Some(
                     context
@@ -742,7 +815,8 @@ impl FunctionCall {
             } => {
                 if output_size > 1 {
                     anyhow::bail!(
-                        "Verbatim instructions with multiple return values are not supported"
+                        "{} Verbatim instructions with multiple return values are not supported",
+                        location
                     );
                 }
 
@@ -750,13 +824,14 @@ impl FunctionCall {
                 let identifier = arguments[0]
                     .original
                     .take()
-                    .ok_or_else(|| anyhow::anyhow!("Verbatim literal is missing"))?;
+                    .ok_or_else(|| anyhow::anyhow!("{} Verbatim literal is missing", location))?;
                 match identifier.as_str() {
                     identifier @ "to_l1" => {
                         const ARGUMENTS_COUNT: usize = 3;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -776,7 +851,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 0;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -789,7 +865,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 2;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -808,7 +885,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 0;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -821,7 +899,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 3;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -841,7 +920,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 5;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -863,7 +943,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 1;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -881,7 +962,8 @@ impl FunctionCall {
                         const ARGUMENTS_COUNT: usize = 0;
                         if input_size != ARGUMENTS_COUNT {
                             anyhow::bail!(
-                                "Internal function `{}` expected {} arguments, found {}",
+                                "{} Internal function `{}` expected {} arguments, found {}",
+                                location,
                                 identifier,
                                 ARGUMENTS_COUNT,
                                 input_size
@@ -890,7 +972,11 @@ impl FunctionCall {
 
                         compiler_llvm_context::verbatim::throw(context)
                     }
-                    identifier => anyhow::bail!("Found unknown internal function `{}`", identifier),
+                    identifier => anyhow::bail!(
+                        "{} Found unknown internal function `{}`",
+                        location,
+                        identifier
+                    ),
                 }
             }
 
@@ -922,9 +1008,30 @@ impl FunctionCall {
     where
         D: compiler_llvm_context::Dependency,
     {
+        if self.arguments.len() < N {
+            anyhow::bail!(
+                "{} Function `{}` expected {} arguments, found {}",
+                self.location,
+                self.name,
+                N,
+                self.arguments.len()
+            );
+        }
+
         let mut arguments = Vec::with_capacity(N);
         for expression in self.arguments.drain(0..N) {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists").value);
+            arguments.push(
+                expression
+                    .into_llvm(context)?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{} Function `{}` argument has no value",
+                            self.location,
+                            self.name
+                        )
+                    })?
+                    .value,
+            );
         }
 
         Ok(arguments.try_into().expect("Always successful"))
@@ -940,9 +1047,25 @@ impl FunctionCall {
     where
         D: compiler_llvm_context::Dependency,
     {
+        if self.arguments.len() < N {
+            anyhow::bail!(
+                "{} Function `{}` expected {} arguments, found {}",
+                self.location,
+                self.name,
+                N,
+                self.arguments.len()
+            );
+        }
+
         let mut arguments = Vec::with_capacity(N);
         for expression in self.arguments.drain(0..N) {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists"));
+            arguments.push(expression.into_llvm(context)?.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{} Function `{}` argument has no value",
+                    self.location,
+                    self.name
+                )
+            })?);
         }
 
         Ok(arguments.try_into().expect("Always successful"))
@@ -960,13 +1083,49 @@ impl FunctionCall {
     where
         D: compiler_llvm_context::Dependency,
     {
+        if self.arguments.len() < N {
+            anyhow::bail!(
+                "{} Function `{}` expected {} arguments, found {}",
+                self.location,
+                self.name,
+                N,
+                self.arguments.len()
+            );
+        }
+
         self.arguments[2..].reverse();
         let mut arguments = Vec::with_capacity(N);
         for expression in self.arguments.drain(0..N) {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists").value);
+            arguments.push(
+                expression
+                    .into_llvm(context)?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{} Function `{}` argument has no value",
+                            self.location,
+                            self.name
+                        )
+                    })?
+                    .value,
+            );
         }
         arguments[2..].reverse();
 
         Ok(arguments.try_into().expect("Always successful"))
     }
 }
+
+impl std::fmt::Display for FunctionCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({})",
+            self.name,
+            self.arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
@@ -209,6 +209,129 @@ pub enum Name {
 }
 
 impl Name {
+    ///
+    /// Returns the number of input arguments of the built-in function.
+    ///
+    /// Returns `None` for the user-defined functions, which must be resolved in their scope.
+    ///
+    pub fn input_size(&self) -> Option<usize> {
+        match self {
+            Self::UserDefined(_) => None,
+            Self::Verbatim { input_size, .. } => Some(*input_size + 1),
+            Self::CallDataSize
+            | Self::CodeSize
+            | Self::ReturnDataSize
+            | Self::Stop
+            | Self::Invalid
+            | Self::Address
+            | Self::Caller
+            | Self::Timestamp
+            | Self::Number
+            | Self::Gas
+            | Self::GasLimit
+            | Self::GasPrice
+            | Self::CallValue
+            | Self::MSize
+            | Self::Origin
+            | Self::ChainId
+            | Self::Difficulty
+            | Self::Pc
+            | Self::SelfBalance
+            | Self::CoinBase
+            | Self::BaseFee => Some(0),
+            Self::IsZero
+            | Self::Not
+            | Self::Pop
+            | Self::MLoad
+            | Self::SLoad
+            | Self::LoadImmutable
+            | Self::CallDataLoad
+            | Self::ExtCodeSize
+            | Self::ExtCodeHash
+            | Self::DataOffset
+            | Self::DataSize
+            | Self::LinkerSymbol
+            | Self::MemoryGuard
+            | Self::Balance
+            | Self::BlockHash
+            | Self::SelfDestruct => Some(1),
+            Self::Add
+            | Self::Sub
+            | Self::Mul
+            | Self::Div
+            | Self::Mod
+            | Self::Sdiv
+            | Self::Smod
+            | Self::Lt
+            | Self::Gt
+            | Self::Eq
+            | Self::Slt
+            | Self::Sgt
+            | Self::Or
+            | Self::Xor
+            | Self::And
+            | Self::Shl
+            | Self::Shr
+            | Self::Sar
+            | Self::Byte
+            | Self::Exp
+            | Self::SignExtend
+            | Self::Keccak256
+            | Self::MStore
+            | Self::MStore8
+            | Self::SStore
+            | Self::Return
+            | Self::Revert
+            | Self::Log0 => Some(2),
+            Self::AddMod
+            | Self::MulMod
+            | Self::SetImmutable
+            | Self::CallDataCopy
+            | Self::CodeCopy
+            | Self::ReturnDataCopy
+            | Self::Log1
+            | Self::Create
+            | Self::DataCopy => Some(3),
+            Self::Log2 | Self::Create2 | Self::ExtCodeCopy => Some(4),
+            Self::Log3 => Some(5),
+            Self::Log4 | Self::StaticCall | Self::DelegateCall => Some(6),
+            Self::Call | Self::CallCode => Some(7),
+        }
+    }
+
+    ///
+    /// Returns the number of return values of the built-in function.
+    ///
+    /// Returns `None` for the user-defined functions, which must be resolved in their scope.
+    ///
+    pub fn output_size(&self) -> Option<usize> {
+        match self {
+            Self::UserDefined(_) => None,
+            Self::Verbatim { output_size, .. } => Some(*output_size),
+            Self::Pop
+            | Self::MStore
+            | Self::MStore8
+            | Self::SStore
+            | Self::SetImmutable
+            | Self::CallDataCopy
+            | Self::CodeCopy
+            | Self::ReturnDataCopy
+            | Self::Return
+            | Self::Revert
+            | Self::Stop
+            | Self::Invalid
+            | Self::Log0
+            | Self::Log1
+            | Self::Log2
+            | Self::Log3
+            | Self::Log4
+            | Self::DataCopy
+            | Self::ExtCodeCopy
+            | Self::SelfDestruct => Some(0),
+            _ => Some(1),
+        }
+    }
+
     ///
     /// Tries parsing the verbatim instruction.
     ///
@@ -336,3 +459,116 @@ impl From<&str> for Name {
         }
     }
 }
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserDefined(inner) => write!(f, "{}", inner),
+
+            Self::Add => write!(f, "add"),
+            Self::Sub => write!(f, "sub"),
+            Self::Mul => write!(f, "mul"),
+            Self::Div => write!(f, "div"),
+            Self::Mod => write!(f, "mod"),
+            Self::Sdiv => write!(f, "sdiv"),
+            Self::Smod => write!(f, "smod"),
+
+            Self::Lt => write!(f, "lt"),
+            Self::Gt => write!(f, "gt"),
+            Self::Eq => write!(f, "eq"),
+            Self::IsZero => write!(f, "iszero"),
+            Self::Slt => write!(f, "slt"),
+            Self::Sgt => write!(f, "sgt"),
+
+            Self::Or => write!(f, "or"),
+            Self::Xor => write!(f, "xor"),
+            Self::Not => write!(f, "not"),
+            Self::And => write!(f, "and"),
+            Self::Shl => write!(f, "shl"),
+            Self::Shr => write!(f, "shr"),
+            Self::Sar => write!(f, "sar"),
+            Self::Byte => write!(f, "byte"),
+            Self::Pop => write!(f, "pop"),
+
+            Self::AddMod => write!(f, "addmod"),
+            Self::MulMod => write!(f, "mulmod"),
+            Self::Exp => write!(f, "exp"),
+            Self::SignExtend => write!(f, "signextend"),
+
+            Self::Keccak256 => write!(f, "keccak256"),
+
+            Self::MLoad => write!(f, "mload"),
+            Self::MStore => write!(f, "mstore"),
+            Self::MStore8 => write!(f, "mstore8"),
+
+            Self::SLoad => write!(f, "sload"),
+            Self::SStore => write!(f, "sstore"),
+            Self::LoadImmutable => write!(f, "loadimmutable"),
+            Self::SetImmutable => write!(f, "setimmutable"),
+
+            Self::CallDataLoad => write!(f, "calldataload"),
+            Self::CallDataSize => write!(f, "calldatasize"),
+            Self::CallDataCopy => write!(f, "calldatacopy"),
+            Self::CodeSize => write!(f, "codesize"),
+            Self::CodeCopy => write!(f, "codecopy"),
+            Self::ExtCodeSize => write!(f, "extcodesize"),
+            Self::ReturnDataSize => write!(f, "returndatasize"),
+            Self::ReturnDataCopy => write!(f, "returndatacopy"),
+
+            Self::Return => write!(f, "return"),
+            Self::Revert => write!(f, "revert"),
+
+            Self::Log0 => write!(f, "log0"),
+            Self::Log1 => write!(f, "log1"),
+            Self::Log2 => write!(f, "log2"),
+            Self::Log3 => write!(f, "log3"),
+            Self::Log4 => write!(f, "log4"),
+
+            Self::Call => write!(f, "call"),
+            Self::CallCode => write!(f, "callcode"),
+            Self::DelegateCall => write!(f, "delegatecall"),
+            Self::StaticCall => write!(f, "staticcall"),
+
+            Self::Create => write!(f, "create"),
+            Self::Create2 => write!(f, "create2"),
+            Self::DataSize => write!(f, "datasize"),
+            Self::DataOffset => write!(f, "dataoffset"),
+            Self::DataCopy => write!(f, "datacopy"),
+
+            Self::Stop => write!(f, "stop"),
+            Self::Invalid => write!(f, "invalid"),
+
+            Self::LinkerSymbol => write!(f, "linkersymbol"),
+            Self::MemoryGuard => write!(f, "memoryguard"),
+
+            Self::Address => write!(f, "address"),
+            Self::Caller => write!(f, "caller"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Number => write!(f, "number"),
+            Self::Gas => write!(f, "gas"),
+
+            Self::GasLimit => write!(f, "gaslimit"),
+            Self::GasPrice => write!(f, "gasprice"),
+            Self::CallValue => write!(f, "callvalue"),
+            Self::MSize => write!(f, "msize"),
+            Self::Origin => write!(f, "origin"),
+            Self::ChainId => write!(f, "chainid"),
+            Self::BlockHash => write!(f, "blockhash"),
+
+            Self::Difficulty => write!(f, "difficulty"),
+            Self::Pc => write!(f, "pc"),
+            Self::Balance => write!(f, "balance"),
+            Self::SelfBalance => write!(f, "selfbalance"),
+            Self::CoinBase => write!(f, "coinbase"),
+            Self::BaseFee => write!(f, "basefee"),
+            Self::ExtCodeCopy => write!(f, "extcodecopy"),
+            Self::ExtCodeHash => write!(f, "extcodehash"),
+            Self::SelfDestruct => write!(f, "selfdestruct"),
+
+            Self::Verbatim {
+                input_size,
+                output_size,
+            } => write!(f, "verbatim_{}i_{}o", input_size, output_size),
+        }
+    }
+}
@@ -9,6 +9,8 @@ use crate::yul::lexer::lexeme::literal::integer::Integer as IntegerLiteral;
 use crate::yul::lexer::lexeme::literal::Literal as LexicalLiteral;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::r#type::Type;
 
@@ -17,6 +19,8 @@ use crate::yul::parser::r#type::Type;
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Literal {
+    /// The location.
+    pub location: Location,
     /// The lexical literal.
     pub inner: LexicalLiteral,
     /// The type, if it has been explicitly specified.
@@ -27,18 +31,30 @@ impl Literal {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let literal = match lexeme {
-            Lexeme::Literal(literal) => literal,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{literal}"], lexeme);
+        let (location, literal) = match token {
+            Token {
+                location,
+                lexeme: Lexeme::Literal(literal),
+                ..
+            } => (location, literal),
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["{literal}"],
+                    token.lexeme
+                );
             }
         };
 
         let yul_type = match lexer.peek()? {
-            Lexeme::Symbol(Symbol::Colon) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Colon),
+                ..
+            } => {
                 lexer.next()?;
                 Some(Type::parse(lexer, None)?)
             }
@@ -46,6 +62,7 @@ impl Literal {
         };
 
         Ok(Self {
+            location,
             inner: literal,
             yul_type,
         })
@@ -147,3 +164,19 @@ impl Literal {
         }
     }
 }
+
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.inner {
+            LexicalLiteral::String(ref inner) if inner.is_hexadecimal => {
+                write!(f, "hex\"{}\"", inner.inner)?
+            }
+            LexicalLiteral::String(ref inner) => write!(f, "\"{}\"", inner.inner)?,
+            ref inner => write!(f, "{}", inner)?,
+        }
+        if let Some(ref yul_type) = self.yul_type {
+            write!(f, ":{}", yul_type)?;
+        }
+        Ok(())
+    }
+}
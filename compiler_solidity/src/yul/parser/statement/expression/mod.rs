@@ -7,7 +7,10 @@ pub mod literal;
 
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::identifier::Identifier;
 
 use self::function_call::FunctionCall;
 use self::literal::Literal;
@@ -20,7 +23,7 @@ pub enum Expression {
     /// The function call subexpression.
     FunctionCall(FunctionCall),
     /// The identifier operand.
-    Identifier(String),
+    Identifier(Identifier),
     /// The literal operand.
     Literal(Literal),
 }
@@ -29,30 +32,53 @@ impl Expression {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let identifier = match lexeme {
-            Lexeme::Literal(_) => return Ok(Self::Literal(Literal::parse(lexer, Some(lexeme))?)),
-            Lexeme::Identifier(identifier) => identifier,
-            lexeme => {
+        let (location, identifier) = match token {
+            token @ Token {
+                lexeme: Lexeme::Literal(_),
+                ..
+            } => return Ok(Self::Literal(Literal::parse(lexer, Some(token))?)),
+            Token {
+                location,
+                lexeme: Lexeme::Identifier(identifier),
+                ..
+            } => (location, identifier),
+            token => {
                 anyhow::bail!(
-                    "Expected one of {:?}, found `{}`",
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
                     ["{literal}", "{identifier}"],
-                    lexeme
+                    token.lexeme
                 );
             }
         };
+        let length = identifier.len();
 
         match lexer.peek()? {
-            Lexeme::Symbol(Symbol::ParenthesisLeft) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                ..
+            } => {
                 lexer.next()?;
                 Ok(Self::FunctionCall(FunctionCall::parse(
                     lexer,
-                    Some(Lexeme::Identifier(identifier)),
+                    Some(Token::new(location, Lexeme::Identifier(identifier), length)),
                 )?))
             }
-            _ => Ok(Self::Identifier(identifier)),
+            _ => Ok(Self::Identifier(Identifier::new(location, identifier))),
+        }
+    }
+
+    ///
+    /// Returns the expression location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::FunctionCall(inner) => inner.location,
+            Self::Identifier(inner) => inner.location,
+            Self::Literal(inner) => inner.location,
         }
     }
 
@@ -72,10 +98,14 @@ impl Expression {
                 let pointer = context
                     .function()
                     .stack
-                    .get(inner.as_str())
+                    .get(inner.name.as_str())
                     .copied()
-                    .ok_or_else(|| anyhow::anyhow!("Undeclared variable `{}`", inner.as_str()))?;
-                Ok(Some(context.build_load(pointer, inner.as_str()).into()))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("{} Undeclared variable `{}`", inner.location, inner.name)
+                    })?;
+                Ok(Some(
+                    context.build_load(pointer, inner.name.as_str()).into(),
+                ))
             }
             Self::FunctionCall(inner) => Ok(inner
                 .into_llvm(context)?
@@ -83,3 +113,13 @@ impl Expression {
         }
     }
 }
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionCall(inner) => write!(f, "{}", inner),
+            Self::Identifier(inner) => write!(f, "{}", inner),
+            Self::Literal(inner) => write!(f, "{}", inner),
+        }
+    }
+}
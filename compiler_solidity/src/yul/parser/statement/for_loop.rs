@@ -2,7 +2,10 @@
 //! The for-loop statement.
 //!
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
@@ -12,6 +15,8 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct ForLoop {
+    /// The location.
+    pub location: Location,
     /// The index variables initialization block.
     pub initializer: Block,
     /// The continue condition block.
@@ -26,10 +31,24 @@ impl ForLoop {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
-
-        let initializer = Block::parse(lexer, Some(lexeme))?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::For),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["for"],
+                token.lexeme
+            ),
+        };
+
+        let initializer = Block::parse(lexer, None)?;
 
         let condition = Expression::parse(lexer, None)?;
 
@@ -38,6 +57,7 @@ impl ForLoop {
         let body = Block::parse(lexer, None)?;
 
         Ok(Self {
+            location,
             initializer,
             condition,
             finalizer,
@@ -95,3 +115,13 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for ForLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "for {} {} {} {}",
+            self.initializer, self.condition, self.finalizer, self.body
+        )
+    }
+}
@@ -4,8 +4,11 @@
 
 use inkwell::types::BasicType;
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::identifier::Identifier;
 use crate::yul::parser::statement::block::Block;
@@ -15,6 +18,8 @@ use crate::yul::parser::statement::block::Block;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct FunctionDefinition {
+    /// The location.
+    pub location: Location,
     /// The function name.
     pub name: String,
     /// The function formal arguments.
@@ -29,25 +34,59 @@ impl FunctionDefinition {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let name = match lexeme {
-            Lexeme::Identifier(name) => name,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{identifier}"], lexeme);
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Function),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["function"],
+                token.lexeme
+            ),
+        };
+
+        let name = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Identifier(name),
+                ..
+            } => name,
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["{identifier}"],
+                    token.lexeme
+                );
             }
         };
 
         match lexer.next()? {
-            Lexeme::Symbol(Symbol::ParenthesisLeft) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["("], lexeme),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                ..
+            } => {}
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["("],
+                token.lexeme
+            ),
         }
 
         let (mut arguments, next) = Identifier::parse_typed_list(lexer, None)?;
         if name.contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX) {
             if arguments.is_empty() {
-                anyhow::bail!("The `{}` function must have at least one argument", name);
+                anyhow::bail!(
+                    "{} The `{}` function must have at least one argument",
+                    location,
+                    name
+                );
             }
 
             arguments.remove(0);
@@ -55,28 +94,48 @@ impl FunctionDefinition {
         if name.contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_EXCEPTION_HANDLER)
             && !arguments.is_empty()
         {
-            anyhow::bail!("The `{}` function cannot have arguments", name);
+            anyhow::bail!("{} The `{}` function cannot have arguments", location, name);
         }
 
         match crate::yul::parser::take_or_next(next, lexer)? {
-            Lexeme::Symbol(Symbol::ParenthesisRight) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", [")"], lexeme),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                ..
+            } => {}
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                [")"],
+                token.lexeme
+            ),
         }
 
         let (result, next) = match lexer.peek()? {
-            Lexeme::Symbol(Symbol::Arrow) => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Arrow),
+                ..
+            } => {
                 lexer.next()?;
                 Identifier::parse_typed_list(lexer, None)?
             }
-            Lexeme::Symbol(Symbol::BracketCurlyLeft) => (vec![], None),
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["->", "{"], lexeme);
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => (vec![], None),
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["->", "{"],
+                    token.lexeme
+                );
             }
         };
 
         let body = Block::parse(lexer, next)?;
 
         Ok(Self {
+            location,
             name,
             arguments,
             result,
@@ -112,11 +171,19 @@ where
                 .functions
                 .get(self.name.as_str())
                 .cloned()
-                .expect("Always exists");
+                .ok_or_else(|| {
+                    anyhow::anyhow!("{} Undeclared function `{}`", self.location, self.name)
+                })?;
             let pointer = function
                 .value
                 .get_first_param()
-                .expect("Always exists")
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{} Function `{}` return pointer parameter is missing",
+                        self.location,
+                        self.name
+                    )
+                })?
                 .into_pointer_value();
             context.set_function(function);
             context.set_function_return(compiler_llvm_context::FunctionReturn::compound(
@@ -129,18 +196,25 @@ where
     }
 
     fn into_llvm(mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let location = self.location;
         let function = context
             .functions
             .get(self.name.as_str())
             .cloned()
-            .expect("Function always exists");
+            .ok_or_else(|| anyhow::anyhow!("{} Undeclared function `{}`", location, self.name))?;
         context.set_function(function.clone());
 
         context.set_basic_block(function.entry_block);
         let r#return = match function.r#return {
             Some(r#return) => {
                 for (index, identifier) in self.result.into_iter().enumerate() {
-                    let pointer = r#return.return_pointer().expect("Always exists");
+                    let pointer = r#return.return_pointer().ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{} Function `{}` return pointer is missing",
+                            location,
+                            self.name
+                        )
+                    })?;
                     let pointer = unsafe {
                         context.builder().build_gep(
                             pointer,
@@ -209,7 +283,14 @@ where
                     .function()
                     .value
                     .get_nth_param(index as u32)
-                    .expect("Always exists"),
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{} Function `{}` argument `{}` parameter is missing",
+                            location,
+                            self.name,
+                            argument.name
+                        )
+                    })?,
             );
         }
 
@@ -246,3 +327,30 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for FunctionDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "function {}({})",
+            self.name,
+            self.arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+        if !self.result.is_empty() {
+            write!(
+                f,
+                " -> {}",
+                self.result
+                    .iter()
+                    .map(|result| result.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
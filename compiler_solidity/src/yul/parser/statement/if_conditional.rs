@@ -2,7 +2,10 @@
 //! The if-conditional statement.
 //!
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
@@ -12,6 +15,8 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct IfConditional {
+    /// The location.
+    pub location: Location,
     /// The condition expression.
     pub condition: Expression,
     /// The conditional block.
@@ -22,14 +27,32 @@ impl IfConditional {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let condition = Expression::parse(lexer, Some(lexeme))?;
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::If),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["if"],
+                token.lexeme
+            ),
+        };
+
+        let condition = Expression::parse(lexer, None)?;
 
         let block = Block::parse(lexer, None)?;
 
-        Ok(Self { condition, block })
+        Ok(Self {
+            location,
+            condition,
+            block,
+        })
     }
 }
 
@@ -66,3 +89,9 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for IfConditional {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "if {} {}", self.condition, self.block)
+    }
+}
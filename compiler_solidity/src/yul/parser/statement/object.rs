@@ -2,20 +2,32 @@
 //! The YUL object.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
+use crate::diagnostic::code::Code as DiagnosticCode;
+use crate::diagnostic::list::List as DiagnosticList;
+use crate::diagnostic::Diagnostic;
 use crate::yul::lexer::lexeme::keyword::Keyword;
+use crate::yul::lexer::lexeme::literal::string::String as StringLiteral;
 use crate::yul::lexer::lexeme::literal::Literal;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::solidity::Location as SolidityLocation;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::code::Code;
+use crate::yul::visitor::location_eraser::LocationEraser;
+use crate::yul::visitor::mutable::VisitorMut;
 
 ///
 /// The YUL object.
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Object {
+    /// The location.
+    pub location: Location,
     /// The identifier.
     pub identifier: String,
     /// The code.
@@ -24,80 +36,203 @@ pub struct Object {
     pub inner_object: Option<Box<Self>>,
     /// The factory dependency objects.
     pub factory_dependencies: HashSet<String>,
+    /// The nested factory dependency objects, which are only kept to print the object.
+    pub dependencies: Vec<Self>,
+    /// The data sections, such as the `solc` metadata.
+    pub data: BTreeMap<String, StringLiteral>,
+    /// The Solidity source file paths, taken from the `@use-src` annotations.
+    pub source_paths: BTreeMap<usize, String>,
 }
 
 impl Object {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        match lexeme {
-            Lexeme::Keyword(Keyword::Object) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["object"], lexeme),
-        }
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Object),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["object"],
+                token.lexeme
+            ),
+        };
 
         let identifier = match lexer.next()? {
-            Lexeme::Literal(Literal::String(literal)) => literal.inner,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{string}"], lexeme);
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal.inner,
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["{string}"],
+                    token.lexeme
+                );
             }
         };
         let is_runtime_code = identifier.ends_with("_deployed");
 
         match lexer.next()? {
-            Lexeme::Symbol(Symbol::BracketCurlyLeft) => {}
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["{"], lexeme),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => {}
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["{"],
+                token.lexeme
+            ),
         }
 
         let code = Code::parse(lexer, None)?;
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
+        let mut dependencies = Vec::new();
+        let mut data = BTreeMap::new();
 
         if !is_runtime_code {
             inner_object = match lexer.peek()? {
-                Lexeme::Keyword(Keyword::Object) => {
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::Object),
+                    ..
+                } => {
                     let mut object = Self::parse(lexer, None)?;
                     factory_dependencies.extend(object.factory_dependencies.drain());
                     Some(Box::new(object))
                 }
                 _ => None,
             };
-
-            if let Lexeme::Identifier(identifier) = lexer.peek()? {
-                if identifier.as_str() == "data" {
-                    let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
-                }
-            };
         }
 
         loop {
             match lexer.next()? {
-                Lexeme::Symbol(Symbol::BracketCurlyRight) => break,
-                lexeme @ Lexeme::Keyword(Keyword::Object) => {
-                    let dependency = Self::parse(lexer, Some(lexeme))?;
-                    factory_dependencies.insert(dependency.identifier);
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } => break,
+                token @ Token {
+                    lexeme: Lexeme::Keyword(Keyword::Object),
+                    ..
+                } => {
+                    let dependency = Self::parse(lexer, Some(token))?;
+                    factory_dependencies.insert(dependency.identifier.clone());
+                    dependencies.push(dependency);
                 }
-                Lexeme::Identifier(identifier) if identifier.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
+                Token {
+                    lexeme: Lexeme::Identifier(identifier),
+                    ..
+                } if identifier.as_str() == "data" => {
+                    let (name, value) = Self::parse_data(lexer)?;
+                    data.insert(name, value);
                 }
-                lexeme => {
-                    anyhow::bail!("Expected one of {:?}, found `{}`", ["object", "}"], lexeme);
+                token => {
+                    anyhow::bail!(
+                        "{} Expected one of {:?}, found `{}`",
+                        token.location,
+                        ["object", "data", "}"],
+                        token.lexeme
+                    );
                 }
             }
         }
 
         Ok(Self {
+            location,
             identifier,
             code,
             inner_object,
             factory_dependencies,
+            dependencies,
+            data,
+            source_paths: lexer.source_paths().to_owned(),
         })
     }
+
+    ///
+    /// Parses the data section name and value, which follow the `data` identifier.
+    ///
+    fn parse_data(lexer: &mut Lexer) -> anyhow::Result<(String, StringLiteral)> {
+        let name = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal.inner,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["{string}"],
+                token.lexeme
+            ),
+        };
+
+        let value = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["{string}"],
+                token.lexeme
+            ),
+        };
+
+        Ok((name, value))
+    }
+
+    ///
+    /// Checks whether the objects are equal, regardless of the source code locations.
+    ///
+    pub fn eq_ignore_location(&self, other: &Self) -> bool {
+        let mut this = self.to_owned();
+        let mut other = other.to_owned();
+        LocationEraser::default().visit_object_mut(&mut this);
+        LocationEraser::default().visit_object_mut(&mut other);
+        this == other
+    }
+
+    ///
+    /// Converts the code generation error with a Solidity source code location attached
+    /// into the diagnostic.
+    ///
+    /// The other errors, including the already converted ones, are returned as is.
+    ///
+    pub fn map_solidity_error(
+        error: anyhow::Error,
+        source_paths: &BTreeMap<usize, String>,
+    ) -> anyhow::Error {
+        let solidity_location = match error.downcast_ref::<SolidityLocation>() {
+            Some(solidity_location) => *solidity_location,
+            None => return error,
+        };
+        if error.downcast_ref::<Diagnostic>().is_some()
+            || error.downcast_ref::<DiagnosticList>().is_some()
+        {
+            return error;
+        }
+
+        let message = error
+            .chain()
+            .skip(1)
+            .map(|error| error.to_string())
+            .collect::<Vec<String>>()
+            .join(": ");
+
+        Diagnostic::new_error(DiagnosticCode::CodeGeneration, message)
+            .with_source_location(Some(solidity_location.resolve(source_paths)))
+            .into()
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Object
@@ -123,10 +258,15 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let source_paths = self.source_paths;
         if self.identifier.ends_with("_deployed") {
-            compiler_llvm_context::RuntimeCodeFunction::new(self.code).into_llvm(context)?;
+            compiler_llvm_context::RuntimeCodeFunction::new(self.code)
+                .into_llvm(context)
+                .map_err(|error| Self::map_solidity_error(error, &source_paths))?;
         } else {
-            compiler_llvm_context::DeployCodeFunction::new(self.code).into_llvm(context)?;
+            compiler_llvm_context::DeployCodeFunction::new(self.code)
+                .into_llvm(context)
+                .map_err(|error| Self::map_solidity_error(error, &source_paths))?;
         }
 
         if let Some(object) = self.inner_object {
@@ -136,3 +276,180 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.source_paths.is_empty() {
+            writeln!(
+                f,
+                "/// @use-src {}",
+                self.source_paths
+                    .iter()
+                    .map(|(index, path)| format!(
+                        "{}:\"{}\"",
+                        index,
+                        path.replace('\\', "\\\\").replace('"', "\\\"")
+                    ))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+        }
+
+        writeln!(f, "object \"{}\" {{", self.identifier)?;
+        for line in self.code.to_string().lines() {
+            writeln!(f, "    {}", line)?;
+        }
+
+        if let Some(ref inner_object) = self.inner_object {
+            for line in inner_object.to_string().lines() {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        for dependency in self.dependencies.iter() {
+            for line in dependency.to_string().lines() {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        for (name, value) in self.data.iter() {
+            if value.is_hexadecimal {
+                writeln!(f, "    data \"{}\" hex\"{}\"", name, value.inner)?;
+            } else {
+                writeln!(f, "    data \"{}\" \"{}\"", name, value.inner)?;
+            }
+        }
+
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+
+    use super::Object;
+
+    const SOLC_IR_OPTIMIZED: &str = r#"/// @use-src 0:"contracts/Factory.sol"
+object "Factory_42" {
+    code {
+        {
+            /// @src 0:88:420  "contract Factory {..."
+            let _1 := memoryguard(0x80)
+            mstore(64, _1)
+            if callvalue() { revert(0, 0) }
+            let _2 := datasize("Factory_42_deployed")
+            codecopy(_1, dataoffset("Factory_42_deployed"), _2)
+            return(_1, _2)
+        }
+    }
+    /// @use-src 0:"contracts/Factory.sol"
+    object "Factory_42_deployed" {
+        code {
+            {
+                /// @src 0:88:420  "contract Factory {..."
+                let _1 := memoryguard(0x80)
+                mstore(64, _1)
+                if iszero(lt(calldatasize(), 4))
+                {
+                    switch shr(224, calldataload(0))
+                    case 0xefc81a8c {
+                        if callvalue() { revert(0, 0) }
+                        let _2 := datasize("Child_12")
+                        let _3 := add(_1, _2)
+                        if or(gt(_3, 0xffffffffffffffff), lt(_3, _1)) { panic_error_0x41() }
+                        datacopy(_1, dataoffset("Child_12"), _2)
+                        let address_1 := create(0, _1, sub(_3, _1))
+                        if iszero(address_1) { revert_forward() }
+                        mstore(_3, and(address_1, sub(shl(160, 1), 1)))
+                        return(_3, 32)
+                    }
+                    default { }
+                }
+                revert(0, 0)
+            }
+            function panic_error_0x41()
+            {
+                mstore(0, shl(224, 0x4e487b71))
+                mstore(4, 0x41)
+                revert(0, 0x24)
+            }
+            function revert_forward()
+            {
+                let pos := mload(64)
+                returndatacopy(pos, 0, returndatasize())
+                revert(pos, returndatasize())
+            }
+        }
+        /// @use-src 0:"contracts/Factory.sol"
+        object "Child_12" {
+            code {
+                {
+                    /// @src 0:25:86  "contract Child {..."
+                    let _1 := memoryguard(0x80)
+                    mstore(64, _1)
+                    if callvalue() { revert(0, 0) }
+                    let _2 := datasize("Child_12_deployed")
+                    codecopy(_1, dataoffset("Child_12_deployed"), _2)
+                    return(_1, _2)
+                }
+            }
+            /// @use-src 0:"contracts/Factory.sol"
+            object "Child_12_deployed" {
+                code {
+                    {
+                        /// @src 0:25:86  "contract Child {..."
+                        for { let i := 0 } lt(i, 4) { i := add(i, 1) }
+                        {
+                            if eq(i, 2) { break }
+                            sstore(i, 0x01)
+                        }
+                        revert(0, 0)
+                    }
+                }
+                data ".metadata" hex"a2646970667358221220"
+            }
+        }
+        data ".metadata" hex"a26469706673582212201c"
+    }
+}
+"#;
+
+    fn parse(source: &str) -> Object {
+        Object::parse(&mut Lexer::new(source.to_owned()), None).expect("Always valid")
+    }
+
+    #[test]
+    fn round_trip_solc_ir_optimized() {
+        let object = parse(SOLC_IR_OPTIMIZED);
+        let printed = object.to_string();
+        let reparsed = parse(printed.as_str());
+
+        assert!(object.eq_ignore_location(&reparsed));
+        assert_eq!(printed, reparsed.to_string());
+
+        let inner_object = object.inner_object.as_ref().expect("Always exists");
+        assert_eq!(inner_object.dependencies.len(), 1);
+        assert_eq!(inner_object.data.len(), 1);
+        assert!(object.factory_dependencies.contains("Child_12"));
+    }
+
+    #[test]
+    fn round_trip_dependency_without_inner_object() {
+        let object = parse(
+            r#"object "A" { code { } object "A_deployed" { code { } object "B" { code { } } } }"#,
+        );
+        let reparsed = parse(object.to_string().as_str());
+
+        assert!(object.eq_ignore_location(&reparsed));
+        assert!(reparsed.factory_dependencies.contains("B"));
+    }
+
+    #[test]
+    fn locations_are_compared() {
+        let first = parse(r#"object "A" { code { sstore(0, 1) } }"#);
+        let second = parse("\n\n\nobject \"A\" {\n code {\n\n  sstore(0, 1) } }");
+
+        assert_ne!(first.location, second.location);
+        assert_ne!(first, second);
+        assert!(first.eq_ignore_location(&second));
+    }
+}
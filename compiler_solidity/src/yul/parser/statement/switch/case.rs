@@ -2,7 +2,10 @@
 //! The switch statement case.
 //!
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::literal::Literal;
@@ -12,6 +15,8 @@ use crate::yul::parser::statement::expression::literal::Literal;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Case {
+    /// The location.
+    pub location: Location,
     /// The matched constant.
     pub literal: Literal,
     /// The case block.
@@ -22,18 +27,50 @@ impl Case {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let literal = match lexeme {
-            lexeme @ Lexeme::Literal(_) => Literal::parse(lexer, Some(lexeme))?,
-            lexeme => {
-                anyhow::bail!("Expected one of {:?}, found `{}`", ["{literal}"], lexeme);
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Case),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["case"],
+                token.lexeme
+            ),
+        };
+
+        let literal = match lexer.next()? {
+            token @ Token {
+                lexeme: Lexeme::Literal(_),
+                ..
+            } => Literal::parse(lexer, Some(token))?,
+            token => {
+                anyhow::bail!(
+                    "{} Expected one of {:?}, found `{}`",
+                    token.location,
+                    ["{literal}"],
+                    token.lexeme
+                );
             }
         };
 
         let block = Block::parse(lexer, None)?;
 
-        Ok(Self { literal, block })
+        Ok(Self {
+            location,
+            literal,
+            block,
+        })
+    }
+}
+
+impl std::fmt::Display for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "case {} {}", self.literal, self.block)
     }
 }
@@ -6,6 +6,8 @@ pub mod case;
 
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
@@ -17,6 +19,8 @@ use self::case::Case;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct Switch {
+    /// The location.
+    pub location: Location,
     /// The expression being matched.
     pub expression: Expression,
     /// The non-default cases.
@@ -41,24 +45,44 @@ impl Switch {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Switch),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["switch"],
+                token.lexeme
+            ),
+        };
         let mut state = State::CaseOrDefaultKeyword;
 
-        let expression = Expression::parse(lexer, Some(lexeme.clone()))?;
+        let expression = Expression::parse(lexer, None)?;
         let mut cases = Vec::new();
         let mut default = None;
 
         loop {
             match state {
                 State::CaseOrDefaultKeyword => match lexer.peek()? {
-                    Lexeme::Keyword(Keyword::Case) => state = State::CaseBlock,
-                    Lexeme::Keyword(Keyword::Default) => state = State::DefaultBlock,
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Case),
+                        ..
+                    } => state = State::CaseBlock,
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Default),
+                        ..
+                    } => state = State::DefaultBlock,
                     _ => break,
                 },
                 State::CaseBlock => {
-                    lexer.next()?;
-                    cases.push(Case::parse(lexer, None)?);
+                    let token = lexer.next()?;
+                    cases.push(Case::parse(lexer, Some(token))?);
                     state = State::CaseOrDefaultKeyword;
                 }
                 State::DefaultBlock => {
@@ -70,14 +94,17 @@ impl Switch {
         }
 
         if cases.is_empty() && default.is_none() {
+            let token = lexer.peek()?;
             anyhow::bail!(
-                "Expected one of {:?}, found `{}`",
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
                 ["case", "default"],
-                lexeme
+                token.lexeme
             );
         }
 
         Ok(Self {
+            location,
             expression,
             cases,
             default,
@@ -141,3 +168,16 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for Switch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "switch {}", self.expression)?;
+        for case in self.cases.iter() {
+            write!(f, "\n{}", case)?;
+        }
+        if let Some(ref default) = self.default {
+            write!(f, "\ndefault {}", default)?;
+        }
+        Ok(())
+    }
+}
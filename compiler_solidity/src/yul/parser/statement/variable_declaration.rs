@@ -5,8 +5,11 @@
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
 
+use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::symbol::Symbol;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::identifier::Identifier;
 use crate::yul::parser::statement::expression::Expression;
@@ -16,6 +19,8 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 #[derive(Debug, PartialEq, Clone)]
 pub struct VariableDeclaration {
+    /// The location.
+    pub location: Location,
     /// The variable bindings list.
     pub bindings: Vec<Identifier>,
     /// The variable initializing expression.
@@ -28,21 +33,39 @@ impl VariableDeclaration {
     ///
     pub fn parse(
         lexer: &mut Lexer,
-        initial: Option<Lexeme>,
-    ) -> anyhow::Result<(Self, Option<Lexeme>)> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+        initial: Option<Token>,
+    ) -> anyhow::Result<(Self, Option<Token>)> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let (bindings, next) = Identifier::parse_typed_list(lexer, Some(lexeme))?;
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Let),
+                location,
+                ..
+            } => location,
+            token => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["let"],
+                token.lexeme
+            ),
+        };
+
+        let (bindings, next) = Identifier::parse_typed_list(lexer, None)?;
 
         match crate::yul::parser::take_or_next(next, lexer)? {
-            Lexeme::Symbol(Symbol::Assignment) => {}
-            lexeme => {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Assignment),
+                ..
+            } => {}
+            token => {
                 return Ok((
                     Self {
+                        location,
                         bindings,
                         expression: None,
                     },
-                    Some(lexeme),
+                    Some(token),
                 ))
             }
         }
@@ -51,6 +74,7 @@ impl VariableDeclaration {
 
         Ok((
             Self {
+                location,
                 bindings,
                 expression: Some(expression),
             },
@@ -124,8 +148,12 @@ where
                         != pointer.get_type()
                     {
                         anyhow::bail!(
-                            "Assignment to {:?} received an invalid number of arguments",
+                            "{} Assignment to {:?} received an invalid number of arguments",
+                            self.location,
                             self.bindings
+                                .iter()
+                                .map(|binding| binding.name.as_str())
+                                .collect::<Vec<&str>>()
                         );
                     }
 
@@ -154,7 +182,8 @@ where
                             .copied()
                             .ok_or_else(|| {
                                 anyhow::anyhow!(
-                                    "Assignment to an undeclared variable `{}`",
+                                    "{} Assignment to an undeclared variable `{}`",
+                                    binding.location,
                                     binding.name
                                 )
                             })?;
@@ -170,3 +199,21 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for VariableDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "let {}",
+            self.bindings
+                .iter()
+                .map(|binding| binding.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+        if let Some(ref expression) = self.expression {
+            write!(f, " := {}", expression)?;
+        }
+        Ok(())
+    }
+}
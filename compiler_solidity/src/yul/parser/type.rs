@@ -4,6 +4,7 @@
 
 use crate::yul::lexer::lexeme::keyword::Keyword;
 use crate::yul::lexer::lexeme::Lexeme;
+use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 
 ///
@@ -31,15 +32,20 @@ impl Type {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Lexeme>) -> anyhow::Result<Self> {
-        let lexeme = crate::yul::parser::take_or_next(initial, lexer)?;
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> anyhow::Result<Self> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        match lexeme {
+        match token.lexeme {
             Lexeme::Keyword(Keyword::Bool) => Ok(Self::Bool),
             Lexeme::Keyword(Keyword::Int(bitlength)) => Ok(Self::Int(bitlength)),
             Lexeme::Keyword(Keyword::Uint(bitlength)) => Ok(Self::UInt(bitlength)),
             Lexeme::Identifier(identifier) => Ok(Self::Custom(identifier)),
-            lexeme => anyhow::bail!("Expected one of {:?}, found `{}`", ["{type}"], lexeme),
+            lexeme => anyhow::bail!(
+                "{} Expected one of {:?}, found `{}`",
+                token.location,
+                ["{type}"],
+                lexeme
+            ),
         }
     }
 
@@ -61,3 +67,14 @@ impl Type {
         }
     }
 }
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int(bitlength) => write!(f, "int{}", bitlength),
+            Self::UInt(bitlength) => write!(f, "uint{}", bitlength),
+            Self::Custom(inner) => write!(f, "{}", inner),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//!
+//! The Yul syntax tree location eraser.
+//!
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::mutable::VisitorMut;
+
+///
+/// The Yul syntax tree location eraser.
+///
+/// Resets every location to the default one, so the trees parsed from differently formatted
+/// source code can be compared by their contents.
+///
+#[derive(Debug, Default)]
+pub struct LocationEraser {}
+
+impl VisitorMut for LocationEraser {
+    fn visit_object_mut(&mut self, object: &mut Object) {
+        object.location = Location::default();
+        crate::yul::visitor::mutable::walk_object_mut(self, object);
+        for dependency in object.dependencies.iter_mut() {
+            self.visit_object_mut(dependency);
+        }
+    }
+
+    fn visit_code_mut(&mut self, code: &mut Code) {
+        code.location = Location::default();
+        crate::yul::visitor::mutable::walk_code_mut(self, code);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        block.location = Location::default();
+        crate::yul::visitor::mutable::walk_block_mut(self, block);
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Continue(location)
+            | Statement::Break(location)
+            | Statement::Leave(location) => *location = Location::default(),
+            _ => {}
+        }
+        crate::yul::visitor::mutable::walk_statement_mut(self, statement);
+    }
+
+    fn visit_function_definition_mut(&mut self, function_definition: &mut FunctionDefinition) {
+        function_definition.location = Location::default();
+        crate::yul::visitor::mutable::walk_function_definition_mut(self, function_definition);
+    }
+
+    fn visit_variable_declaration_mut(&mut self, variable_declaration: &mut VariableDeclaration) {
+        variable_declaration.location = Location::default();
+        crate::yul::visitor::mutable::walk_variable_declaration_mut(self, variable_declaration);
+    }
+
+    fn visit_assignment_mut(&mut self, assignment: &mut Assignment) {
+        assignment.location = Location::default();
+        crate::yul::visitor::mutable::walk_assignment_mut(self, assignment);
+    }
+
+    fn visit_if_conditional_mut(&mut self, if_conditional: &mut IfConditional) {
+        if_conditional.location = Location::default();
+        crate::yul::visitor::mutable::walk_if_conditional_mut(self, if_conditional);
+    }
+
+    fn visit_switch_mut(&mut self, switch: &mut Switch) {
+        switch.location = Location::default();
+        crate::yul::visitor::mutable::walk_switch_mut(self, switch);
+    }
+
+    fn visit_case_mut(&mut self, case: &mut Case) {
+        case.location = Location::default();
+        crate::yul::visitor::mutable::walk_case_mut(self, case);
+    }
+
+    fn visit_for_loop_mut(&mut self, for_loop: &mut ForLoop) {
+        for_loop.location = Location::default();
+        crate::yul::visitor::mutable::walk_for_loop_mut(self, for_loop);
+    }
+
+    fn visit_function_call_mut(&mut self, function_call: &mut FunctionCall) {
+        function_call.location = Location::default();
+        crate::yul::visitor::mutable::walk_function_call_mut(self, function_call);
+    }
+
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+        identifier.location = Location::default();
+    }
+
+    fn visit_literal_mut(&mut self, literal: &mut Literal) {
+        literal.location = Location::default();
+    }
+}
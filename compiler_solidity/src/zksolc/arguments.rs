@@ -18,9 +18,15 @@ use structopt::StructOpt;
 #[structopt(name = "The zkEVM Solidity compiler")]
 pub struct Arguments {
     /// The input file paths.
+    /// The `prefix=target` arguments are treated as import remappings.
     #[structopt(parse(from_os_str))]
     pub input_files: Vec<PathBuf>,
 
+    /// The import remapping in the `[context:]prefix=[target]` format,
+    /// which is passed to `solc`. Can be used multiple times.
+    #[structopt(long = "remapping")]
+    pub remappings: Vec<String>,
+
     /// Use the given path as the root of the source tree
     /// instead of the root of the filesystem.
     #[structopt(long = "base-path")]
@@ -46,6 +52,11 @@ pub struct Arguments {
     #[structopt(short = "o", long = "output-dir")]
     pub output_directory: Option<PathBuf>,
 
+    /// Also write a toolchain-specific JSON artifact per contract (used together with -o).
+    /// Available formats: hardhat, foundry
+    #[structopt(long = "artifact-format")]
+    pub artifact_format: Option<compiler_solidity::ArtifactFormat>,
+
     /// Overwrite existing files (used together with -o).
     #[structopt(long = "overwrite")]
     pub overwrite: bool,
@@ -54,18 +65,46 @@ pub struct Arguments {
     #[structopt(long = "optimize")]
     pub optimize: bool,
 
+    /// Embed the `keccak256` hash of the `zksolc` metadata into the bytecode.
+    /// The hash is written to the last bytecode word.
+    #[structopt(long = "metadata-hash")]
+    pub metadata_hash: bool,
+
+    /// The contract bytecode size limit in words.
+    /// Exceeding it produces a warning. Defaults to 65535 words.
+    #[structopt(long = "bytecode-size-limit")]
+    pub bytecode_size_limit: Option<usize>,
+
+    /// Fail instead of warning if the bytecode size limit is exceeded.
+    #[structopt(long = "bytecode-size-limit-error")]
+    pub bytecode_size_limit_error: bool,
+
     /// Sets the LLVM optimizer options.
     #[structopt(long = "llvm-opt")]
     pub llvm_options: Option<String>,
 
+    /// The number of compilation threads. Defaults to the number of CPU cores.
+    /// With 1, the contracts are compiled strictly sequentially in the order of their paths.
+    /// Only then is the output printed during the compilation, such as the LLVM crash
+    /// messages, in a deterministic order. The diagnostics are always reported by path.
+    /// The dump options always use a single thread, so only 1 is allowed with them.
+    #[structopt(long = "threads")]
+    pub threads: Option<usize>,
+
+    /// The maximum number of clones of a single block in the Ethereal IR functions.
+    /// Only affects the EVM legacy assembly pipeline. Defaults to 256.
+    #[structopt(long = "ethir-clone-limit")]
+    pub ethir_clone_limit: Option<usize>,
+
     /// Path to the `solc` executable.
     /// By default, the one in $PATH is used.
     #[structopt(long = "solc")]
     pub solc: Option<String>,
 
     /// Direct string or file containing library addresses.
-    /// Syntax: <libraryName>=<address> [, or whitespace] ...
-    /// Address is interpreted as a hex string prefixed by 0x.
+    /// Syntax: <file>:<libraryName>=<address> [, or whitespace] ...
+    /// A JSON object in the standard JSON `libraries` format is also accepted.
+    /// Address is interpreted as a 20-byte hex string prefixed by 0x.
     #[structopt(short = "l", long = "libraries")]
     pub libraries: Vec<String>,
 
@@ -80,6 +119,20 @@ pub struct Arguments {
     #[structopt(long = "standard-json")]
     pub standard_json: bool,
 
+    /// Recompile the standard JSON input saved in the artifact file and compare
+    /// the bytecode hashes with the expected ones.
+    #[structopt(long = "verify", parse(from_os_str))]
+    pub verify: Option<PathBuf>,
+
+    /// Switch to the linker mode.
+    /// Replaces the library placeholders in the input `.zbin` files with the
+    /// addresses specified with --libraries. The files are modified in place, and the
+    /// new bytecode hashes are written to the build manifest and artifacts listing them.
+    /// The factory dependencies of other contracts cannot be linked, since their hashes
+    /// are embedded into the bytecode of the latter.
+    #[structopt(long = "link")]
+    pub link: bool,
+
     /// Switch to Yul mode.
     #[structopt(long = "yul")]
     pub yul: bool,
@@ -104,6 +157,10 @@ pub struct Arguments {
     #[structopt(long = "bin")]
     pub output_binary: bool,
 
+    /// Write the per-contract compilation timing and statistics JSON report to the file.
+    #[structopt(long = "statistics", parse(from_os_str))]
+    pub statistics: Option<PathBuf>,
+
     /// Dump the Yul Intermediate Representation (IR) of all contracts.
     #[structopt(long = "dump-yul")]
     pub dump_yul: bool,
@@ -116,6 +173,11 @@ pub struct Arguments {
     #[structopt(long = "dump-ethir")]
     pub dump_ethir: bool,
 
+    /// Write the Ethereal IR control-flow graphs of all contracts to the specified directory.
+    /// One Graphviz file is written per contract and code type, e.g. `A.sol_A.runtime.dot`.
+    #[structopt(long = "dump-ethir-dot", parse(from_os_str))]
+    pub dump_ethir_dot: Option<PathBuf>,
+
     /// Dump the LLVM Intermediate Representation (IR) of all contracts.
     #[structopt(long = "dump-llvm")]
     pub dump_llvm: bool,
@@ -130,13 +192,45 @@ impl Arguments {
     /// A shortcut constructor.
     ///
     pub fn new() -> Self {
-        Self::from_args()
+        let mut arguments = Self::from_args();
+
+        let (remappings, input_files): (Vec<PathBuf>, Vec<PathBuf>) = arguments
+            .input_files
+            .drain(..)
+            .partition(|path| path.to_string_lossy().contains('='));
+        arguments.input_files = input_files;
+        arguments.remappings.extend(
+            remappings
+                .into_iter()
+                .map(|path| path.to_string_lossy().to_string()),
+        );
+
+        arguments
     }
 
     ///
     /// Validates the arguments.
     ///
     pub fn validate(&self) -> anyhow::Result<()> {
+        if self.threads == Some(0) {
+            anyhow::bail!("The number of threads must be positive.");
+        }
+        if self.threads.unwrap_or(1) != 1
+            && (self.dump_yul
+                || self.dump_ethir
+                || self.dump_evm
+                || self.dump_llvm
+                || self.dump_assembly)
+        {
+            anyhow::bail!(
+                "The option --threads is invalid with the dump options, which compile the contracts in a single thread."
+            );
+        }
+
+        if self.ethir_clone_limit == Some(0) {
+            anyhow::bail!("The Ethereal IR block clone limit must be positive.");
+        }
+
         if self.yul {
             if self.combined_json.is_some() {
                 anyhow::bail!("The following options are invalid in Yul mode: --combined-json.");
@@ -150,6 +244,73 @@ impl Arguments {
             if self.output_hashes {
                 anyhow::bail!("The following options are invalid in Yul mode: --hashes.");
             }
+            if !self.remappings.is_empty() {
+                anyhow::bail!("The following options are invalid in Yul mode: --remapping.");
+            }
+            if self.verify.is_some() {
+                anyhow::bail!("The following options are invalid in Yul mode: --verify.");
+            }
+            if self.ethir_clone_limit.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in Yul mode: --ethir-clone-limit."
+                );
+            }
+            if self.dump_ethir_dot.is_some() {
+                anyhow::bail!("The following options are invalid in Yul mode: --dump-ethir-dot.");
+            }
+        }
+
+        if self.link {
+            if self.yul {
+                anyhow::bail!("The following options are invalid in linker mode: --yul.");
+            }
+            if self.standard_json {
+                anyhow::bail!("The following options are invalid in linker mode: --standard-json.");
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!("The following options are invalid in linker mode: --combined-json.");
+            }
+            if self.verify.is_some() {
+                anyhow::bail!("The following options are invalid in linker mode: --verify.");
+            }
+            if self.output_directory.is_some() {
+                anyhow::bail!("The following options are invalid in linker mode: --output-dir.");
+            }
+            if self.input_files.is_empty() {
+                anyhow::bail!("The input files are missing in linker mode.");
+            }
+        }
+
+        if self.artifact_format.is_some() {
+            if self.output_directory.is_none() {
+                anyhow::bail!("The option --artifact-format requires --output-dir.");
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid with --artifact-format: --combined-json."
+                );
+            }
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid with --artifact-format: --standard-json."
+                );
+            }
+        }
+
+        if self.verify.is_some() {
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in verification mode: --standard-json."
+                );
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in verification mode: --combined-json."
+                );
+            }
+            if !self.input_files.is_empty() {
+                anyhow::bail!("Input files are invalid in verification mode.");
+            }
         }
 
         Ok(())
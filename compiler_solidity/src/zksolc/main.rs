@@ -42,6 +42,62 @@ fn main_inner() -> anyhow::Result<()> {
         *path = path.canonicalize()?;
     }
 
+    if arguments.link {
+        let libraries =
+            compiler_solidity::SolcStandardJsonInputSettings::parse_libraries(arguments.libraries)?;
+        for path in arguments.input_files.iter() {
+            let manifest = compiler_solidity::Manifest::find(path)?;
+            if let Some((_, manifest, contract_path)) = manifest.as_ref() {
+                let dependents = manifest.dependents(contract_path.as_str());
+                if !dependents.is_empty() {
+                    anyhow::bail!(
+                        "File {:?} cannot be linked, since contract `{}` is a factory dependency of {}, whose bytecode contains its hash. Specify the libraries with --libraries at compile time instead",
+                        path,
+                        contract_path,
+                        dependents
+                            .iter()
+                            .map(|dependent| format!("`{}`", dependent))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    );
+                }
+            }
+
+            let mut bytecode = std::fs::read(path)
+                .map_err(|error| anyhow::anyhow!("File {:?} reading error: {}", path, error))?;
+            let linked = compiler_solidity::link(bytecode.as_mut_slice(), &libraries)?;
+            let hash = compiler_solidity::bytecode_hash(bytecode.as_slice())
+                .map_err(|error| anyhow::anyhow!("File {:?} hashing error: {}", path, error))?;
+            std::fs::write(path, bytecode.as_slice())
+                .map_err(|error| anyhow::anyhow!("File {:?} writing error: {}", path, error))?;
+            let is_manifest_updated = match manifest {
+                Some((directory, manifest, contract_path)) => {
+                    manifest.update_linked(
+                        directory.as_path(),
+                        contract_path.as_str(),
+                        bytecode.as_slice(),
+                        hash.as_str(),
+                        &linked,
+                    )?;
+                    true
+                }
+                None => false,
+            };
+            eprintln!(
+                "File {:?} linked with {} library(ies). New bytecode hash: {}{}",
+                path,
+                linked.len(),
+                hash,
+                if is_manifest_updated {
+                    ""
+                } else {
+                    " (no manifest lists the file, so its factory dependents cannot be checked)"
+                },
+            );
+        }
+        return Ok(());
+    }
+
     let solc =
         compiler_solidity::SolcCompiler::new(arguments.solc.unwrap_or_else(|| {
             compiler_solidity::SolcCompiler::DEFAULT_EXECUTABLE_NAME.to_owned()
@@ -75,6 +131,23 @@ fn main_inner() -> anyhow::Result<()> {
         );
     }
 
+    if let Some(path) = arguments.verify {
+        let artifact = compiler_solidity::VerificationArtifact::try_from_path(path.as_path())?;
+        let report = artifact.verify(
+            &solc,
+            pipeline,
+            arguments.ethir_clone_limit,
+            arguments.base_path,
+            arguments.include_paths,
+            arguments.allow_paths,
+        )?;
+        print!("{}", report);
+        if !report.is_success() {
+            anyhow::bail!("Verification failed");
+        }
+        return Ok(());
+    }
+
     let build = if arguments.yul {
         let path = match arguments.input_files.len() {
             1 => arguments.input_files.remove(0),
@@ -85,49 +158,131 @@ fn main_inner() -> anyhow::Result<()> {
             ),
         };
 
-        let project = compiler_solidity::Project::try_from_default_yul(&path, &solc_version)?;
+        let mut project = compiler_solidity::Project::try_from_default_yul(&path, &solc_version)?;
+        let metadata = compiler_solidity::ProjectMetadata::new(
+            &solc_version,
+            compiler_solidity::SolcPipeline::Yul,
+            arguments.optimize,
+            None,
+            project.yul_source_hashes(),
+        );
+        project.set_metadata(metadata, arguments.metadata_hash);
+        project.set_threads(arguments.threads);
         let optimizer_settings = if arguments.optimize {
             compiler_llvm_context::OptimizerSettings::cycles()
         } else {
             compiler_llvm_context::OptimizerSettings::none()
         };
-        project.compile_all(optimizer_settings, dump_flags)
+        let mut build = project.compile_all(optimizer_settings, dump_flags)?;
+        build.check_bytecode_size(
+            arguments
+                .bytecode_size_limit
+                .unwrap_or(compiler_solidity::Build::BYTECODE_SIZE_LIMIT_DEFAULT),
+            arguments.bytecode_size_limit_error,
+        );
+        if let Some(path) = arguments.statistics.as_deref() {
+            build.write_statistics(path)?;
+        }
+        build.print_warnings();
+        build.check_errors()
     } else {
-        let output_selection =
-            compiler_solidity::SolcStandardJsonInputSettings::get_output_selection(
-                arguments
-                    .input_files
-                    .iter()
-                    .map(|path| path.to_string_lossy().to_string())
-                    .collect(),
-                pipeline,
-            );
-        let solc_input = if arguments.standard_json {
+        let files: Vec<String> = arguments
+            .input_files
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect();
+        let (solc_input, requested_selection) = if arguments.standard_json {
             let mut input: compiler_solidity::SolcStandardJsonInput =
                 serde_json::from_reader(std::io::BufReader::new(std::io::stdin()))?;
-            input.settings.output_selection = output_selection;
-            input
+            let requested_selection = input.settings.merge_output_selection(files, pipeline);
+            if !arguments.remappings.is_empty() {
+                let remappings =
+                    compiler_solidity::SolcStandardJsonInputSettings::parse_remappings(
+                        arguments.remappings.clone(),
+                    )?;
+                input
+                    .settings
+                    .remappings
+                    .get_or_insert_with(Vec::new)
+                    .extend(remappings);
+            }
+            (input, requested_selection)
         } else {
-            compiler_solidity::SolcStandardJsonInput::try_from_paths(
+            let input = compiler_solidity::SolcStandardJsonInput::try_from_paths(
                 compiler_solidity::SolcStandardJsonInputLanguage::Solidity,
                 arguments.input_files.as_slice(),
                 arguments.libraries,
-                output_selection,
+                arguments.remappings.clone(),
+                compiler_solidity::SolcStandardJsonInputSettings::get_output_selection(
+                    files, pipeline,
+                ),
                 true,
-            )?
+            )?;
+            (
+                input,
+                compiler_solidity::SolcStandardJsonInputSettingsRequested::default(),
+            )
         };
 
         let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
+        compiler_solidity::SolcStandardJsonInputSettings::validate_libraries(&libraries)?;
         let optimize = if arguments.standard_json {
-            solc_input.settings.optimizer.enabled
+            solc_input
+                .settings
+                .zksolc
+                .optimize
+                .unwrap_or(solc_input.settings.optimizer.enabled)
         } else {
             arguments.optimize
         };
+        let metadata_hash = if arguments.standard_json {
+            solc_input
+                .settings
+                .zksolc
+                .metadata_hash
+                .unwrap_or(arguments.metadata_hash)
+        } else {
+            arguments.metadata_hash
+        };
+        let (bytecode_size_limit, bytecode_size_limit_error) = if arguments.standard_json {
+            (
+                solc_input
+                    .settings
+                    .zksolc
+                    .bytecode_size_limit
+                    .or(arguments.bytecode_size_limit),
+                solc_input
+                    .settings
+                    .zksolc
+                    .bytecode_size_limit_error
+                    .unwrap_or(arguments.bytecode_size_limit_error),
+            )
+        } else {
+            (
+                arguments.bytecode_size_limit,
+                arguments.bytecode_size_limit_error,
+            )
+        };
+        let ethereal_ir_clone_limit = if arguments.standard_json {
+            solc_input
+                .settings
+                .zksolc
+                .ethereal_ir_clone_limit
+                .or(arguments.ethir_clone_limit)
+        } else {
+            arguments.ethir_clone_limit
+        };
+        let metadata = compiler_solidity::ProjectMetadata::new_from_input(
+            &solc_version,
+            pipeline,
+            optimize,
+            &solc_input,
+        );
         let mut solc_output = solc.standard_json(
             solc_input,
-            arguments.base_path,
-            arguments.include_paths,
-            arguments.allow_paths,
+            arguments.base_path.clone(),
+            arguments.include_paths.clone(),
+            arguments.allow_paths.clone(),
         )?;
 
         if let Some(errors) = solc_output.errors.as_deref() {
@@ -136,6 +291,7 @@ fn main_inner() -> anyhow::Result<()> {
                 if error.severity.as_str() == "error" {
                     cannot_compile = true;
                     if arguments.standard_json {
+                        solc_output.remove_selections(&requested_selection);
                         serde_json::to_writer(std::io::stdout(), &solc_output)?;
                         return Ok(());
                     }
@@ -151,24 +307,66 @@ fn main_inner() -> anyhow::Result<()> {
             }
         }
 
-        let project =
-            solc_output.try_to_project(libraries, pipeline, solc_version, dump_flags.as_slice())?;
         let optimizer_settings = if optimize {
             compiler_llvm_context::OptimizerSettings::cycles()
         } else {
             compiler_llvm_context::OptimizerSettings::none()
         };
-        let build = project.compile_all(optimizer_settings, dump_flags)?;
+        let mut build = match solc_output
+            .try_to_project(libraries, pipeline, solc_version, dump_flags.as_slice())
+            .and_then(|mut project| {
+                project.set_metadata(metadata, metadata_hash);
+                project.set_threads(arguments.threads);
+                project.set_ethereal_ir_clone_limit(ethereal_ir_clone_limit);
+                project.set_ethereal_ir_dot_directory(arguments.dump_ethir_dot.clone());
+                project.compile_all(optimizer_settings, dump_flags)
+            }) {
+            Ok(build) => build,
+            Err(error) if arguments.standard_json => {
+                solc_output.push_error(error);
+                solc_output.remove_selections(&requested_selection);
+                serde_json::to_writer(std::io::stdout(), &solc_output)?;
+                return Ok(());
+            }
+            Err(error) => return Err(error),
+        };
+        build.check_bytecode_size(
+            bytecode_size_limit.unwrap_or(compiler_solidity::Build::BYTECODE_SIZE_LIMIT_DEFAULT),
+            bytecode_size_limit_error,
+        );
+        if let Some(path) = arguments.statistics.as_deref() {
+            build.write_statistics(path)?;
+        }
         if arguments.standard_json {
             build.write_to_standard_json(&mut solc_output)?;
+            solc_output.remove_selections(&requested_selection);
             serde_json::to_writer(std::io::stdout(), &solc_output)?;
             return Ok(());
         }
-        Ok(build)
+        build.print_warnings();
+        build.check_errors()
     }?;
 
+    for (path, contract) in build.contracts.iter() {
+        for (file, contracts) in contract.link_references.iter() {
+            for name in contracts.keys() {
+                eprintln!(
+                    "Warning: contract `{}` has the unlinked library `{}:{}`. Use --link to set its address.",
+                    path, file, name
+                );
+            }
+        }
+    }
+
     let combined_json = if let Some(combined_json) = arguments.combined_json {
-        Some(solc.combined_json(arguments.input_files.as_slice(), combined_json.as_str())?)
+        Some(solc.combined_json(
+            arguments.input_files.as_slice(),
+            combined_json.as_str(),
+            arguments.remappings.as_slice(),
+            arguments.base_path.clone(),
+            arguments.include_paths.clone(),
+            arguments.allow_paths.clone(),
+        )?)
     } else {
         None
     };
@@ -185,6 +383,7 @@ fn main_inner() -> anyhow::Result<()> {
                 arguments.output_assembly,
                 arguments.output_binary,
                 arguments.output_abi,
+                arguments.artifact_format,
                 arguments.overwrite,
             )?;
         }
@@ -225,6 +424,10 @@ fn main_inner() -> anyhow::Result<()> {
                 arguments.input_files.as_slice(),
                 arguments.output_abi,
                 arguments.output_hashes,
+                arguments.remappings.as_slice(),
+                arguments.base_path.clone(),
+                arguments.include_paths.clone(),
+                arguments.allow_paths.clone(),
             )?;
             print!("{}", extra_output);
         }